@@ -0,0 +1,58 @@
+//! Compare converting public keys one at a time with the batch conversion.
+//!
+//! ```text
+//! cargo bench --bench batch
+//! ```
+use ed25519_to_curve25519::*;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const BATCH_SIZES: [usize; 4] = [1, 16, 256, 10_000];
+const ROUNDS: usize = 5;
+
+/// Run `f` `ROUNDS` times and return the fastest run.
+fn fastest<F: FnMut()>(mut f: F) -> Duration {
+    (0..ROUNDS)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+fn main() {
+    // Distinct public keys, derived from distinct seeds.
+    let mut seed = [0u8; 32];
+    let pks: Vec<[u8; 32]> = (0..*BATCH_SIZES.iter().max().unwrap())
+        .map(|i| {
+            seed[..8].copy_from_slice(&(i as u64).to_le_bytes());
+            ed25519_public_from_seed(seed)
+        })
+        .collect();
+
+    for &n in BATCH_SIZES.iter() {
+        let pks = &pks[..n];
+        let mut out = vec![[0u8; 32]; n];
+
+        let single = fastest(|| {
+            for (pk, u) in pks.iter().zip(out.iter_mut()) {
+                *u = ed25519_pk_to_curve25519(black_box(*pk));
+            }
+            black_box(&out);
+        });
+        let batch = fastest(|| {
+            batch_ed25519_pk_to_curve25519(black_box(pks), &mut out);
+            black_box(&out);
+        });
+
+        println!(
+            "{:>6} keys: single {:>9.2?}/key, batch {:>9.2?}/key, speedup {:.1}x",
+            n,
+            single / n as u32,
+            batch / n as u32,
+            single.as_secs_f64() / batch.as_secs_f64()
+        );
+    }
+}
@@ -0,0 +1,47 @@
+//! Little-endian encodings of the curve constants.
+//!
+//! The field backends have no `const` constructors, so the constants are
+//! stored as bytes and loaded with `FieldElement::from_bytes` where needed.
+
+/// Edwards `d` value, equal to `-121665/121666 mod p`.
+pub const EDWARDS_D: [u8; 32] = [
+    163, 120, 89, 19, 202, 77, 235, 117, 171, 216, 65, 65, 77, 10, 112, 0, 152, 232, 121, 119, 121,
+    64, 199, 140, 115, 254, 111, 43, 238, 108, 3, 82,
+];
+
+/// Edwards `2*d` value, used in point addition.
+pub const EDWARDS_D2: [u8; 32] = [
+    89, 241, 178, 38, 148, 155, 214, 235, 86, 177, 131, 130, 154, 20, 224, 0, 48, 209, 243, 238,
+    242, 128, 142, 25, 231, 252, 223, 86, 220, 217, 6, 36,
+];
+
+/// Precomputed value of one of the square roots of -1 (mod p).
+pub const SQRT_M1: [u8; 32] = [
+    176, 160, 14, 74, 39, 27, 238, 196, 120, 228, 47, 173, 6, 24, 67, 47, 167, 215, 251, 61, 153,
+    0, 77, 43, 11, 223, 193, 79, 128, 36, 131, 43,
+];
+
+/// Montgomery curve constant `A = 486662`.
+pub const MONTGOMERY_A: [u8; 32] = [
+    0x06, 0x6d, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+];
+
+/// The order of the Ed25519 base point, `l = 2^252 + 27742317777372353535851937790883648493`.
+pub const BASEPOINT_ORDER: [u8; 32] = [
+    0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+];
+
+/// The x-coordinate of the Ed25519 base point.
+pub const ED25519_BASEPOINT_X: [u8; 32] = [
+    26, 213, 37, 143, 96, 45, 86, 201, 178, 167, 37, 149, 96, 199, 44, 105, 92, 220, 214, 253, 49,
+    226, 164, 192, 254, 83, 110, 205, 211, 54, 105, 33,
+];
+
+/// The y-coordinate of the Ed25519 base point, `4/5`. This is also the
+/// compressed encoding of the base point, since its x-coordinate is even.
+pub const ED25519_BASEPOINT_Y: [u8; 32] = [
+    88, 102, 102, 102, 102, 102, 102, 102, 102, 102, 102, 102, 102, 102, 102, 102, 102, 102, 102,
+    102, 102, 102, 102, 102, 102, 102, 102, 102, 102, 102, 102, 102,
+];
@@ -0,0 +1,119 @@
+//! Constant-time selection and comparison.
+//!
+//! With the `subtle` feature these are the types and traits of the
+//! `subtle` crate. Otherwise a minimal implementation with the same
+//! interface is used, so the rest of the crate is written once against
+//! either.
+
+#[cfg(feature = "subtle")]
+pub use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+#[cfg(not(feature = "subtle"))]
+pub use self::fallback::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+#[cfg(not(feature = "subtle"))]
+mod fallback {
+    use core::ops::{BitAnd, BitOr, Not};
+
+    /// A boolean stored as a `u8` that is either 0 or 1.
+    ///
+    /// The value is passed through `black_box` on construction, so the
+    /// optimizer cannot turn masked selections back into branches.
+    #[derive(Copy, Clone, Debug)]
+    pub struct Choice(u8);
+
+    impl Choice {
+        /// Return the underlying value, 0 or 1.
+        pub fn unwrap_u8(&self) -> u8 {
+            self.0
+        }
+    }
+
+    impl From<u8> for Choice {
+        fn from(input: u8) -> Choice {
+            debug_assert!(input == 0 || input == 1);
+            Choice(core::hint::black_box(input))
+        }
+    }
+
+    impl From<Choice> for bool {
+        fn from(source: Choice) -> bool {
+            source.0 != 0
+        }
+    }
+
+    impl BitAnd for Choice {
+        type Output = Choice;
+        fn bitand(self, rhs: Choice) -> Choice {
+            (self.0 & rhs.0).into()
+        }
+    }
+
+    impl BitOr for Choice {
+        type Output = Choice;
+        fn bitor(self, rhs: Choice) -> Choice {
+            (self.0 | rhs.0).into()
+        }
+    }
+
+    impl Not for Choice {
+        type Output = Choice;
+        fn not(self) -> Choice {
+            (1 ^ self.0).into()
+        }
+    }
+
+    /// Equality that does not short-circuit.
+    pub trait ConstantTimeEq {
+        /// Return 1 if `self == other` and 0 otherwise.
+        fn ct_eq(&self, other: &Self) -> Choice;
+    }
+
+    impl ConstantTimeEq for [u8] {
+        fn ct_eq(&self, other: &[u8]) -> Choice {
+            if self.len() != other.len() {
+                return Choice::from(0);
+            }
+
+            let mut acc = 0u8;
+            for (a, b) in self.iter().zip(other.iter()) {
+                acc |= a ^ b;
+            }
+
+            // acc is zero iff the slices are equal
+            Choice::from((((acc as u16).wrapping_sub(1) >> 8) & 1) as u8)
+        }
+    }
+
+    /// Selection between two values without branching on the condition.
+    pub trait ConditionallySelectable: Copy {
+        /// Return `a` if `choice` is 0 and `b` if `choice` is 1.
+        fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self;
+
+        /// Set `self` to `other` if `choice` is 1.
+        fn conditional_assign(&mut self, other: &Self, choice: Choice) {
+            *self = Self::conditional_select(self, other, choice);
+        }
+
+        /// Swap `a` and `b` if `choice` is 1.
+        fn conditional_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+            let t: Self = *a;
+            a.conditional_assign(b, choice);
+            b.conditional_assign(&t, choice);
+        }
+    }
+
+    impl ConditionallySelectable for u32 {
+        fn conditional_select(a: &u32, b: &u32, choice: Choice) -> u32 {
+            let mask = (choice.unwrap_u8() as u32).wrapping_neg();
+            a ^ (mask & (a ^ b))
+        }
+    }
+
+    impl ConditionallySelectable for u64 {
+        fn conditional_select(a: &u64, b: &u64, choice: Choice) -> u64 {
+            let mask = (choice.unwrap_u8() as u64).wrapping_neg();
+            a ^ (mask & (a ^ b))
+        }
+    }
+}
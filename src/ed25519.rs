@@ -0,0 +1,85 @@
+//! Ed25519 signatures, from RFC 8032, section 5.1.
+#![allow(clippy::all)]
+#![allow(non_snake_case)]
+use crate::edwards::EdwardsPoint;
+use crate::error::Error;
+use crate::montgomery;
+use crate::scalar::Scalar;
+use crate::sha512::{self, Sha512};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// `SHA-512(R || A || M) (mod l)`, the challenge of sections 5.1.6 and 5.1.7.
+fn challenge(R: &[u8; 32], A: &[u8; 32], msg: &[u8]) -> Scalar {
+    let mut hasher = Sha512::new();
+    hasher.update(R);
+    hasher.update(A);
+    hasher.update(msg);
+    Scalar::from_bytes_mod_order_wide(&hasher.finalize())
+}
+
+/// Sign `msg` with the 32-byte secret key `seed` (section 5.1.6).
+#[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+pub fn sign(seed: &[u8; 32], msg: &[u8]) -> [u8; 64] {
+    #[cfg(feature = "zeroize")]
+    let h = sha512::sha512_zeroizing(seed);
+    #[cfg(not(feature = "zeroize"))]
+    let h = sha512::sha512(seed);
+
+    let mut a_bytes = [0u8; 32];
+    a_bytes.copy_from_slice(&h[..32]);
+    a_bytes = montgomery::clamp_scalar(a_bytes);
+    let A = EdwardsPoint::mul_base(&a_bytes).compress();
+    let mut a = Scalar::from_bytes_mod_order(a_bytes);
+
+    // r = SHA-512(prefix || M) (mod l)
+    let mut hasher = Sha512::new();
+    hasher.update(&h[32..]);
+    hasher.update(msg);
+    let mut r = Scalar::from_bytes_mod_order_wide(&hasher.finalize());
+    let R = EdwardsPoint::mul_base(r.as_bytes()).compress();
+
+    let k = challenge(&R, &A, msg);
+    let S = Scalar::mul_add(&k, &a, &r);
+
+    #[cfg(feature = "zeroize")]
+    {
+        a_bytes.zeroize();
+        a.zeroize();
+        r.zeroize();
+    }
+
+    let mut signature = [0u8; 64];
+    signature[..32].copy_from_slice(&R);
+    signature[32..].copy_from_slice(S.as_bytes());
+    signature
+}
+
+/// Verify the signature `R || S` on `msg` under the public key `A`
+/// (section 5.1.7), with the cofactorless equation `[S]B = R + [k]A`.
+///
+/// `S` must already be checked to be reduced modulo `l`. With `strict`,
+/// public keys and `R` of small order are rejected as well.
+pub fn verify(
+    A: &[u8; 32],
+    msg: &[u8],
+    R: &[u8; 32],
+    S: &[u8; 32],
+    strict: bool,
+) -> Result<(), Error> {
+    let A_point = EdwardsPoint::decompress(A)?;
+    let R_point = EdwardsPoint::decompress(R)?;
+
+    if strict && (A_point.is_small_order() || R_point.is_small_order()) {
+        return Err(Error::SmallOrder);
+    }
+
+    // R == [S]B - [k]A, compared as encodings
+    let k = challenge(R, A, msg);
+    let R_check = &EdwardsPoint::mul_base(S) + &(-&A_point.mul(k.as_bytes()));
+    if R_check.compress() != *R {
+        return Err(Error::InvalidSignature);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,254 @@
+// The point arithmetic and the decompression routine are adapted from
+// curve25519-dalek (src/edwards.rs), <https://github.com/dalek-cryptography/curve25519-dalek>,
+// which is distributed under the following licence (BSD-3-Clause):
+//
+// Copyright (c) 2016-2021 isis agora lovecruft. All rights reserved.
+// Copyright (c) 2016-2021 Henry de Valence. All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are
+// met:
+//
+// 1. Redistributions of source code must retain the above copyright
+// notice, this list of conditions and the following disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright
+// notice, this list of conditions and the following disclaimer in the
+// documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its
+// contributors may be used to endorse or promote products derived from
+// this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS
+// IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED
+// TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A
+// PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT
+// HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED
+// TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+#![allow(clippy::all)]
+#![allow(non_snake_case)]
+use crate::constants;
+use crate::ct::{Choice, ConditionallySelectable, ConstantTimeEq};
+use crate::error::Error;
+use crate::field::FieldElement;
+use core::ops::{Add, Neg};
+
+/// A point on the twisted Edwards form of Curve25519, in extended
+/// coordinates: x = X/Z, y = Y/Z, xy = T/Z.
+#[derive(Copy, Clone)]
+pub struct EdwardsPoint {
+    pub X: FieldElement,
+    pub Y: FieldElement,
+    pub Z: FieldElement,
+    pub T: FieldElement,
+}
+
+impl<'a, 'b> Add<&'b EdwardsPoint> for &'a EdwardsPoint {
+    type Output = EdwardsPoint;
+    fn add(self, other: &'b EdwardsPoint) -> EdwardsPoint {
+        // add-2008-hwcd-3 addition formulas for a = -1, k = 2d.
+        let d2 = FieldElement::from_bytes(&constants::EDWARDS_D2);
+
+        let A = &(&self.Y - &self.X) * &(&other.Y - &other.X);
+        let B = &(&self.Y + &self.X) * &(&other.Y + &other.X);
+        let C = &(&self.T * &d2) * &other.T;
+        let D = &self.Z * &(&other.Z + &other.Z);
+        let E = &B - &A;
+        let F = &D - &C;
+        let G = &D + &C;
+        let H = &B + &A;
+
+        EdwardsPoint {
+            X: &E * &F,
+            Y: &G * &H,
+            Z: &F * &G,
+            T: &E * &H,
+        }
+    }
+}
+
+impl<'a> Neg for &'a EdwardsPoint {
+    type Output = EdwardsPoint;
+    fn neg(self) -> EdwardsPoint {
+        EdwardsPoint {
+            X: -&self.X,
+            Y: self.Y,
+            Z: self.Z,
+            T: -&self.T,
+        }
+    }
+}
+
+impl ConditionallySelectable for EdwardsPoint {
+    fn conditional_select(a: &EdwardsPoint, b: &EdwardsPoint, choice: Choice) -> EdwardsPoint {
+        EdwardsPoint {
+            X: FieldElement::conditional_select(&a.X, &b.X, choice),
+            Y: FieldElement::conditional_select(&a.Y, &b.Y, choice),
+            Z: FieldElement::conditional_select(&a.Z, &b.Z, choice),
+            T: FieldElement::conditional_select(&a.T, &b.T, choice),
+        }
+    }
+}
+
+impl EdwardsPoint {
+    /// Construct the identity point (0, 1).
+    pub fn identity() -> EdwardsPoint {
+        EdwardsPoint {
+            X: FieldElement::zero(),
+            Y: FieldElement::one(),
+            Z: FieldElement::one(),
+            T: FieldElement::zero(),
+        }
+    }
+
+    /// Construct the Ed25519 base point.
+    pub fn basepoint() -> EdwardsPoint {
+        let X = FieldElement::from_bytes(&constants::ED25519_BASEPOINT_X);
+        let Y = FieldElement::from_bytes(&constants::ED25519_BASEPOINT_Y);
+
+        EdwardsPoint {
+            X,
+            Y,
+            Z: FieldElement::one(),
+            T: &X * &Y,
+        }
+    }
+
+    /// Decode a 32-byte compressed Edwards point.
+    ///
+    /// Decoding follows RFC 8032, section 5.1.3: the y-coordinate must be
+    /// canonical, x is recovered as a square root of (y^2 - 1)/(dy^2 + 1),
+    /// and a set sign bit is rejected when x = 0.
+    pub fn decompress(bytes: &[u8; 32]) -> Result<EdwardsPoint, Error> {
+        let Y = FieldElement::from_bytes(bytes);
+
+        let mut y_bytes = *bytes;
+        y_bytes[31] &= 0x7f;
+        if Y.to_bytes() != y_bytes {
+            return Err(Error::NonCanonicalEncoding);
+        }
+
+        let Z = FieldElement::one();
+        let d = FieldElement::from_bytes(&constants::EDWARDS_D);
+        let YY = Y.square();
+        let u = &YY - &Z; //  u =  y²-1
+        let v = &(&YY * &d) + &Z; //  v = dy²+1
+
+        let (is_valid_y_coord, mut X) = FieldElement::sqrt_ratio_i(&u, &v);
+        if !bool::from(is_valid_y_coord) {
+            return Err(Error::NotOnCurve);
+        }
+
+        // sqrt_ratio_i always returns the nonnegative square root,
+        // so we negate according to the supplied sign bit.
+        let compressed_sign_bit = Choice::from(bytes[31] >> 7);
+        if bool::from(compressed_sign_bit & X.is_zero()) {
+            return Err(Error::InvalidSignBit);
+        }
+        X.conditional_negate(compressed_sign_bit);
+
+        Ok(EdwardsPoint {
+            X,
+            Y,
+            Z,
+            T: &X * &Y,
+        })
+    }
+
+    /// Encode this point as 32 bytes: the y-coordinate, with the sign of
+    /// the x-coordinate in the top bit.
+    pub fn compress(&self) -> [u8; 32] {
+        let recip = self.Z.invert();
+        let x = &self.X * &recip;
+        let y = &self.Y * &recip;
+
+        let mut s = y.to_bytes();
+        s[31] ^= x.is_negative().unwrap_u8() << 7;
+
+        s
+    }
+
+    /// Check whether this point is the identity (0, 1).
+    pub fn is_identity(&self) -> bool {
+        (self.X.is_zero() & self.Y.ct_eq(&self.Z)).into()
+    }
+
+    /// Add this point to itself.
+    pub fn double(&self) -> EdwardsPoint {
+        // dbl-2008-hwcd doubling formulas for a = -1, computed in the
+        // completed coordinates ((E:G), (H:F)) and converted back.
+        let XX = self.X.square();
+        let YY = self.Y.square();
+        let ZZ = self.Z.square();
+        let X_plus_Y_sq = (&self.X + &self.Y).square();
+        let YY_plus_XX = &YY + &XX;
+        let YY_minus_XX = &YY - &XX;
+
+        let E = &X_plus_Y_sq - &YY_plus_XX;
+        let F = &(&ZZ + &ZZ) - &YY_minus_XX;
+
+        EdwardsPoint {
+            X: &E * &F,
+            Y: &YY_plus_XX * &YY_minus_XX,
+            Z: &YY_minus_XX * &F,
+            T: &E * &YY_plus_XX,
+        }
+    }
+
+    /// Compute [k]P for a 256-bit little-endian scalar `k`.
+    ///
+    /// This is a double-and-always-add loop: the sum is computed for
+    /// every bit and kept with a conditional assignment, so the running
+    /// time does not depend on the scalar.
+    pub fn mul(&self, k: &[u8; 32]) -> EdwardsPoint {
+        let mut R = EdwardsPoint::identity();
+
+        for i in (0..256).rev() {
+            R = R.double();
+            let S = &R + self;
+            R.conditional_assign(&S, Choice::from((k[i >> 3] >> (i & 7)) & 1));
+        }
+
+        R
+    }
+
+    /// Compute [k]B, where B is the Ed25519 base point.
+    pub fn mul_base(k: &[u8; 32]) -> EdwardsPoint {
+        EdwardsPoint::basepoint().mul(k)
+    }
+
+    /// Multiply by the cofactor: return [8]P.
+    pub fn mul_by_cofactor(&self) -> EdwardsPoint {
+        self.double().double().double()
+    }
+
+    /// Determine if this point is of small order, i.e. one of the
+    /// eight points of the torsion subgroup E[8].
+    pub fn is_small_order(&self) -> bool {
+        self.mul_by_cofactor().is_identity()
+    }
+
+    /// Determine if this point is in the prime-order subgroup, i.e.
+    /// [l]P is the identity.
+    pub fn is_torsion_free(&self) -> bool {
+        self.mul(&constants::BASEPOINT_ORDER).is_identity()
+    }
+
+    /// Map this point to the u-coordinate of the birationally equivalent
+    /// Montgomery point, u = (1 + y)/(1 - y) = (Z + Y)/(Z - Y).
+    ///
+    /// The identity has no image and maps to zero.
+    pub fn to_montgomery(&self) -> FieldElement {
+        let U = &self.Z + &self.Y;
+        let W = &self.Z - &self.Y;
+
+        &U * &W.invert()
+    }
+}
@@ -0,0 +1,57 @@
+use core::fmt;
+
+/// Errors returned by the fallible functions of this crate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The encoded coordinate is not reduced modulo 2^255 - 19, or has bit 255 set.
+    NonCanonicalEncoding,
+    /// There is no x-coordinate for the encoded y-coordinate.
+    NotOnCurve,
+    /// The sign bit is set, but the decoded x-coordinate is zero.
+    InvalidSignBit,
+    /// The point is the identity, which has no Montgomery u-coordinate.
+    Identity,
+    /// The point is one of the eight points of small order.
+    SmallOrder,
+    /// The point has a small-order component, so it is not in the prime-order subgroup.
+    NotPrimeOrder,
+    /// The scalar is not reduced modulo the group order.
+    InvalidScalar,
+    /// The scalar is not clamped: bits 0, 1, 2 and 255 must be clear and bit 254 set.
+    UnclampedScalar,
+    /// The secret key does not have the expected length.
+    InvalidSecretKeyLength,
+    /// The signature does not have the expected length.
+    InvalidSignatureLength,
+    /// The secret key does not belong to the public key.
+    KeyMismatch,
+    /// The requested HKDF output is longer than 255 hash blocks.
+    InvalidOutputLength,
+    /// The signature does not verify under the public key.
+    InvalidSignature,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match self {
+            Error::NonCanonicalEncoding => "non-canonical point encoding",
+            Error::NotOnCurve => "point is not on the curve",
+            Error::InvalidSignBit => "sign bit is set for x = 0",
+            Error::Identity => "point is the identity",
+            Error::SmallOrder => "point has small order",
+            Error::NotPrimeOrder => "point is not in the prime-order subgroup",
+            Error::InvalidScalar => "scalar is not reduced modulo the group order",
+            Error::UnclampedScalar => "scalar is not clamped",
+            Error::InvalidSecretKeyLength => "invalid secret key length",
+            Error::InvalidSignatureLength => "invalid signature length",
+            Error::KeyMismatch => "secret key does not match public key",
+            Error::InvalidOutputLength => "requested output is too long",
+            Error::InvalidSignature => "signature verification failed",
+        };
+
+        f.write_str(description)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
@@ -1,9 +1,98 @@
+// `ct_eq`, `is_negative`, `is_zero`, `conditional_negate`, `pow22501`, `invert`,
+// `pow_p58` and `sqrt_ratio_i` are adapted from curve25519-dalek (src/field.rs),
+// <https://github.com/dalek-cryptography/curve25519-dalek>, which is distributed
+// under the following licence (BSD-3-Clause):
+//
+// Copyright (c) 2016-2021 isis agora lovecruft. All rights reserved.
+// Copyright (c) 2016-2021 Henry de Valence. All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are
+// met:
+//
+// 1. Redistributions of source code must retain the above copyright
+// notice, this list of conditions and the following disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright
+// notice, this list of conditions and the following disclaimer in the
+// documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its
+// contributors may be used to endorse or promote products derived from
+// this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS
+// IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED
+// TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A
+// PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT
+// HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED
+// TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
 #![allow(clippy::all)]
-use crate::field_element_2625::FieldElement2625;
+use crate::constants;
+use crate::ct::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+// The field backend: `FieldElement51` on 64-bit targets and
+// `FieldElement2625` elsewhere, unless a backend is forced with the
+// `u32_backend` or `u64_backend` feature (`u32_backend` wins if both
+// are enabled).
+#[cfg(any(
+    feature = "u32_backend",
+    all(not(feature = "u64_backend"), not(target_pointer_width = "64"))
+))]
+pub type FieldElement = crate::field_element_2625::FieldElement2625;
+
+#[cfg(all(
+    not(feature = "u32_backend"),
+    any(feature = "u64_backend", target_pointer_width = "64")
+))]
+pub type FieldElement = crate::field_element_51::FieldElement51;
 
-pub type FieldElement = FieldElement2625;
+impl ConstantTimeEq for FieldElement {
+    /// Test equality between two `FieldElement`s.  Since the
+    /// internal representation is not canonical, the field elements
+    /// are normalized to wire format before comparison.
+    fn ct_eq(&self, other: &FieldElement) -> Choice {
+        self.to_bytes()[..].ct_eq(&other.to_bytes()[..])
+    }
+}
+
+impl PartialEq for FieldElement {
+    fn eq(&self, other: &FieldElement) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for FieldElement {}
 
 impl FieldElement {
+    /// Determine if this `FieldElement` is negative, in the sense
+    /// used in the ed25519 paper: `x` is negative if the low bit is
+    /// set.
+    pub fn is_negative(&self) -> Choice {
+        let bytes = self.to_bytes();
+        (bytes[0] & 1).into()
+    }
+
+    /// Determine if this `FieldElement` is zero.
+    pub fn is_zero(&self) -> Choice {
+        let zero = [0u8; 32];
+        let bytes = self.to_bytes();
+
+        bytes[..].ct_eq(&zero[..])
+    }
+
+    /// Negate `self` if `choice` is 1, in constant time.
+    pub fn conditional_negate(&mut self, choice: Choice) {
+        let self_neg = -&*self;
+        self.conditional_assign(&self_neg, choice);
+    }
+
     /// Compute (self^(2^250-1), self^11)
     fn pow22501(&self) -> (FieldElement, FieldElement) {
         // Instead of managing which temporary variables are used
@@ -47,6 +136,8 @@ impl FieldElement {
     /// x^(p-2)x = x^(p-1) = 1 (mod p).
     ///
     /// This function returns zero on input zero.
+    ///
+    /// The exponent is fixed, so this runs in constant time.
     pub fn invert(&self) -> FieldElement {
         // The bits of p-2 = 2^255 -19 -2 are 11010111111...11.
         //
@@ -57,4 +148,86 @@ impl FieldElement {
 
         t21
     }
+
+    /// Raise this field element to the power (p-5)/8 = 2^252 -3.
+    fn pow_p58(&self) -> FieldElement {
+        // The bits of (p-5)/8 are 101111.....11.
+        //
+        //                                 nonzero bits of exponent
+        let (t19, _) = self.pow22501(); //    249..0
+        let t20 = t19.pow2k(2); //            251..2
+        let t21 = self * &t20; //             251..2,0
+
+        t21
+    }
+
+    /// Given `FieldElements` `u` and `v`, compute either `sqrt(u/v)`
+    /// or `sqrt(i*u/v)` in constant time.
+    ///
+    /// This function always returns the nonnegative square root.
+    ///
+    /// # Return
+    ///
+    /// - `(Choice(1), +sqrt(u/v))  ` if `v` is nonzero and `u/v` is square;
+    /// - `(Choice(1), zero)        ` if `u` is zero;
+    /// - `(Choice(0), zero)        ` if `v` is zero and `u` is nonzero;
+    /// - `(Choice(0), +sqrt(i*u/v))` if `u/v` is nonsquare (so `i*u/v` is square).
+    pub fn sqrt_ratio_i(u: &FieldElement, v: &FieldElement) -> (Choice, FieldElement) {
+        // Using the same trick as in ed25519 decoding, we merge the
+        // inversion, the square root, and the square test as follows.
+        //
+        // To compute sqrt(α), we can compute β = α^((p+3)/8).
+        // Then β^2 = ±α, so multiplying β by sqrt(-1) if necessary
+        // gives sqrt(α).
+        //
+        // To compute 1/sqrt(α), we observe that
+        //    1/β = α^(p-1 - (p+3)/8) = α^((7p-11)/8)
+        //                            = α^3 * (α^7)^((p-5)/8).
+        //
+        // We can therefore compute sqrt(u/v) = sqrt(u)/sqrt(v)
+        // by first computing
+        //    r = u^((p+3)/8) v^(p-1-(p+3)/8)
+        //      = u u^((p-5)/8) v^3 (v^7)^((p-5)/8)
+        //      = (uv^3) (uv^7)^((p-5)/8).
+        //
+        // If v is nonzero and u/v is square, then r^2 = ±u/v,
+        //                                     so vr^2 = ±u.
+        // If vr^2 =  u, then sqrt(u/v) = r.
+        // If vr^2 = -u, then sqrt(u/v) = r*sqrt(-1).
+        //
+        // If v is zero, r is also zero.
+        let sqrt_m1 = FieldElement::from_bytes(&constants::SQRT_M1);
+
+        let v3 = &v.square() * v;
+        let v7 = &v3.square() * v;
+        let mut r = &(u * &v3) * &(u * &v7).pow_p58();
+        let check = v * &r.square();
+
+        let neg_u = -u;
+        let correct_sign_sqrt = check.ct_eq(u);
+        let flipped_sign_sqrt = check.ct_eq(&neg_u);
+        let flipped_sign_sqrt_i = check.ct_eq(&(&neg_u * &sqrt_m1));
+
+        let r_prime = &sqrt_m1 * &r;
+        r.conditional_assign(&r_prime, flipped_sign_sqrt | flipped_sign_sqrt_i);
+
+        // Choose the nonnegative square root.
+        let r_is_negative = r.is_negative();
+        r.conditional_negate(r_is_negative);
+
+        let was_nonzero_square = correct_sign_sqrt | flipped_sign_sqrt;
+
+        (was_nonzero_square, r)
+    }
+
+    /// Determine if this `FieldElement` is a square, in constant time.
+    ///
+    /// Zero counts as a square. This is `sqrt_ratio_i(self, 1)` without
+    /// the root, so it uses the same `pow22501` exponentiation chain
+    /// as `invert`.
+    pub fn is_square(&self) -> Choice {
+        let (is_square, _) = FieldElement::sqrt_ratio_i(self, &FieldElement::one());
+
+        is_square
+    }
 }
@@ -1,4 +1,40 @@
+// Adapted from the u32 field backend of curve25519-dalek
+// (src/backend/serial/u32/field.rs), <https://github.com/dalek-cryptography/curve25519-dalek>,
+// which is distributed under the following licence (BSD-3-Clause):
+//
+// Copyright (c) 2016-2021 isis agora lovecruft. All rights reserved.
+// Copyright (c) 2016-2021 Henry de Valence. All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are
+// met:
+//
+// 1. Redistributions of source code must retain the above copyright
+// notice, this list of conditions and the following disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright
+// notice, this list of conditions and the following disclaimer in the
+// documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its
+// contributors may be used to endorse or promote products derived from
+// this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS
+// IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED
+// TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A
+// PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT
+// HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED
+// TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
 #![allow(clippy::all)]
+use crate::ct::{Choice, ConditionallySelectable};
+use core::ops::Neg;
 use core::ops::{Add, AddAssign};
 use core::ops::{Mul, MulAssign};
 use core::ops::{Sub, SubAssign};
@@ -54,6 +90,27 @@ impl<'a, 'b> Sub<&'b FieldElement2625> for &'a FieldElement2625 {
     }
 }
 
+impl<'a> Neg for &'a FieldElement2625 {
+    type Output = FieldElement2625;
+    fn neg(self) -> FieldElement2625 {
+        &FieldElement2625::zero() - self
+    }
+}
+
+impl ConditionallySelectable for FieldElement2625 {
+    fn conditional_select(
+        a: &FieldElement2625,
+        b: &FieldElement2625,
+        choice: Choice,
+    ) -> FieldElement2625 {
+        let mut output = [0u32; 10];
+        for i in 0..10 {
+            output[i] = u32::conditional_select(&a.0[i], &b.0[i], choice);
+        }
+        FieldElement2625(output)
+    }
+}
+
 impl<'b> MulAssign<&'b FieldElement2625> for FieldElement2625 {
     fn mul_assign(&mut self, _rhs: &'b FieldElement2625) {
         let result = (self as &FieldElement2625) * _rhs;
@@ -307,6 +364,9 @@ impl FieldElement2625 {
 
     /// Serialize this `FieldElement51` to a 32-byte array.  The
     /// encoding is canonical.
+    ///
+    /// The reduction is straight-line code, so this runs in constant
+    /// time.
     pub fn to_bytes(&self) -> [u8; 32] {
         let inp = &self.0;
         // Reduce the value represented by `in` to the range [0,2*p)
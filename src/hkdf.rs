@@ -0,0 +1,86 @@
+//! HKDF-SHA512 (RFC 5869), built on [`crate::hmac::HmacSha512`].
+#![allow(clippy::all)]
+use crate::error::Error;
+use crate::hmac::{hmac_sha512, HmacSha512};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// Longest output `hkdf_sha512_expand` can produce: 255 blocks of 64 bytes.
+pub const MAX_OUTPUT_LEN: usize = 255 * 64;
+
+/// HKDF-Extract: derive a pseudorandom key from `ikm`.
+///
+/// An empty `salt` is the same as 64 zero bytes.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::hkdf::hkdf_sha512_extract;
+/// let prk = hkdf_sha512_extract(b"salt", b"shared secret");
+/// assert_eq!(prk.len(), 64);
+/// ```
+///
+pub fn hkdf_sha512_extract(salt: &[u8], ikm: &[u8]) -> [u8; 64] {
+    hmac_sha512(salt, ikm)
+}
+
+/// HKDF-Expand: fill `okm` with key material derived from `prk` and `info`.
+///
+/// Returns `Error::InvalidOutputLength` if `okm` is longer than
+/// `MAX_OUTPUT_LEN`.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::hkdf::{hkdf_sha512_expand, hkdf_sha512_extract};
+/// let prk = hkdf_sha512_extract(b"salt", b"shared secret");
+/// let mut okm = [0u8; 32];
+/// hkdf_sha512_expand(&prk, b"context", &mut okm).unwrap();
+/// ```
+///
+pub fn hkdf_sha512_expand(prk: &[u8; 64], info: &[u8], okm: &mut [u8]) -> Result<(), Error> {
+    if okm.len() > MAX_OUTPUT_LEN {
+        return Err(Error::InvalidOutputLength);
+    }
+
+    let mut t = [0u8; 64];
+    for (i, chunk) in okm.chunks_mut(64).enumerate() {
+        let mut mac = HmacSha512::new(prk);
+        if i > 0 {
+            mac.update(&t);
+        }
+        mac.update(info);
+        mac.update(&[i as u8 + 1]);
+        t = mac.finalize();
+
+        chunk.copy_from_slice(&t[..chunk.len()]);
+    }
+
+    #[cfg(feature = "zeroize")]
+    t.zeroize();
+
+    Ok(())
+}
+
+/// HKDF-Extract followed by HKDF-Expand.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::hkdf::hkdf_sha512;
+/// use ed25519_to_curve25519::*;
+/// let alice = [1u8; 32];
+/// let bob = [2u8; 32];
+/// let shared = x25519(alice, x25519_base(bob));
+/// let mut key = [0u8; 32];
+/// hkdf_sha512(b"", &shared, b"session key", &mut key).unwrap();
+/// ```
+///
+pub fn hkdf_sha512(salt: &[u8], ikm: &[u8], info: &[u8], okm: &mut [u8]) -> Result<(), Error> {
+    #[cfg(feature = "zeroize")]
+    let prk = zeroize::Zeroizing::new(hkdf_sha512_extract(salt, ikm));
+    #[cfg(not(feature = "zeroize"))]
+    let prk = hkdf_sha512_extract(salt, ikm);
+
+    hkdf_sha512_expand(&prk, info, okm)
+}
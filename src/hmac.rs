@@ -0,0 +1,89 @@
+//! HMAC-SHA512 (RFC 2104), built on [`crate::sha512::Sha512`].
+#![allow(clippy::all)]
+use crate::sha512::Sha512;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+const BLOCK_SIZE: usize = 128;
+
+/// Incremental HMAC-SHA512.
+///
+/// With the `zeroize` feature, the key-dependent state is wiped when the
+/// MAC is dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::hmac::{hmac_sha512, HmacSha512};
+/// let mut mac = HmacSha512::new(b"key");
+/// mac.update(b"hello ");
+/// mac.update(b"world");
+/// assert_eq!(mac.finalize(), hmac_sha512(b"key", b"hello world"));
+/// ```
+///
+#[derive(Clone)]
+pub struct HmacSha512 {
+    inner: Sha512,
+    outer: Sha512,
+}
+
+impl HmacSha512 {
+    /// Construct a MAC for an empty message. Keys longer than the block
+    /// size are hashed first.
+    pub fn new(key: &[u8]) -> HmacSha512 {
+        let mut block = [0u8; BLOCK_SIZE];
+        if key.len() > BLOCK_SIZE {
+            #[cfg(feature = "zeroize")]
+            let hashed = crate::sha512::sha512_zeroizing(key);
+            #[cfg(not(feature = "zeroize"))]
+            let hashed = crate::sha512::sha512(key);
+            block[..64].copy_from_slice(&hashed[..]);
+        } else {
+            block[..key.len()].copy_from_slice(key);
+        }
+
+        let mut inner = Sha512::new();
+        let mut outer = Sha512::new();
+        for byte in block.iter_mut() {
+            *byte ^= 0x36;
+        }
+        inner.update(&block);
+        for byte in block.iter_mut() {
+            *byte ^= 0x36 ^ 0x5c;
+        }
+        outer.update(&block);
+
+        #[cfg(feature = "zeroize")]
+        block.zeroize();
+
+        HmacSha512 { inner, outer }
+    }
+
+    /// Append `data` to the message.
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    /// Return the MAC of the message.
+    pub fn finalize(self) -> [u8; 64] {
+        let HmacSha512 { inner, mut outer } = self;
+        outer.update(&inner.finalize());
+        outer.finalize()
+    }
+}
+
+/// Compute HMAC-SHA512 of `data` under `key`.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::hmac::hmac_sha512;
+/// let tag = hmac_sha512(b"Jefe", b"what do ya want for nothing?");
+/// assert_eq!(tag[..4], [0x16, 0x4b, 0x7a, 0x7b]);
+/// ```
+///
+pub fn hmac_sha512(key: &[u8], data: &[u8]) -> [u8; 64] {
+    let mut mac = HmacSha512::new(key);
+    mac.update(data);
+    mac.finalize()
+}
@@ -0,0 +1,116 @@
+use crate::error::Error;
+use crate::{
+    ed25519_public_from_seed, ed25519_sk_to_curve25519, try_ed25519_pk_to_curve25519, x25519_base,
+};
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// A Curve25519 key pair converted from an Ed25519 key pair.
+///
+/// With the `zeroize` feature, the secret key is wiped when the key pair is dropped.
+#[derive(Clone)]
+pub struct Keypair {
+    secret: [u8; 32],
+    public: [u8; 32],
+}
+
+impl Keypair {
+    /// Convert an Ed25519 seed and the matching Ed25519 public key.
+    ///
+    /// The Ed25519 public key derived from the seed must equal `ed25519_pk`, and the Curve25519
+    /// public key computed from the converted secret key must equal the converted `ed25519_pk`. A
+    /// seed that does not belong to `ed25519_pk` is rejected with [`Error::KeyMismatch`] instead of
+    /// producing an unusable key pair.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ed25519_to_curve25519::*;
+    /// // RFC 8032, section 7.1, TEST 1
+    /// let ed25519_sk = [
+    ///     0x9d, 0x61, 0xb1, 0x9d, 0xef, 0xfd, 0x5a, 0x60, 0xba, 0x84, 0x4a, 0xf4, 0x92, 0xec, 0x2c,
+    ///     0xc4, 0x44, 0x49, 0xc5, 0x69, 0x7b, 0x32, 0x69, 0x19, 0x70, 0x3b, 0xac, 0x03, 0x1c, 0xae,
+    ///     0x7f, 0x60,
+    /// ];
+    /// let ed25519_pk = [
+    ///     0xd7, 0x5a, 0x98, 0x01, 0x82, 0xb1, 0x0a, 0xb7, 0xd5, 0x4b, 0xfe, 0xd3, 0xc9, 0x64, 0x07,
+    ///     0x3a, 0x0e, 0xe1, 0x72, 0xf3, 0xda, 0xa6, 0x23, 0x25, 0xaf, 0x02, 0x1a, 0x68, 0xf7, 0x07,
+    ///     0x51, 0x1a,
+    /// ];
+    /// let keypair = Keypair::from_ed25519(ed25519_sk, ed25519_pk).unwrap();
+    /// assert_eq!(*keypair.secret(), ed25519_sk_to_curve25519(ed25519_sk));
+    /// assert_eq!(*keypair.public(), ed25519_pk_to_curve25519(ed25519_pk));
+    ///
+    /// assert!(Keypair::from_ed25519([0u8; 32], ed25519_pk).is_err());
+    /// ```
+    ///
+    pub fn from_ed25519(seed: [u8; 32], ed25519_pk: [u8; 32]) -> Result<Keypair, Error> {
+        let converted_pk = try_ed25519_pk_to_curve25519(ed25519_pk)?;
+
+        if ed25519_public_from_seed(seed) != ed25519_pk {
+            return Err(Error::KeyMismatch);
+        }
+
+        let secret = ed25519_sk_to_curve25519(seed);
+        let public = x25519_base(secret);
+
+        if converted_pk != public {
+            return Err(Error::KeyMismatch);
+        }
+
+        Ok(Keypair { secret, public })
+    }
+
+    /// Convert a 64-byte Ed25519 secret key, as stored by libsodium and TweetNaCl.
+    ///
+    /// The key is the 32-byte seed followed by the 32-byte public key. The embedded public key is
+    /// checked against the seed as in [`Keypair::from_ed25519`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ed25519_to_curve25519::*;
+    /// // RFC 8032, section 7.1, TEST 1
+    /// let ed25519_sk = [
+    ///     0x9d, 0x61, 0xb1, 0x9d, 0xef, 0xfd, 0x5a, 0x60, 0xba, 0x84, 0x4a, 0xf4, 0x92, 0xec, 0x2c,
+    ///     0xc4, 0x44, 0x49, 0xc5, 0x69, 0x7b, 0x32, 0x69, 0x19, 0x70, 0x3b, 0xac, 0x03, 0x1c, 0xae,
+    ///     0x7f, 0x60, 0xd7, 0x5a, 0x98, 0x01, 0x82, 0xb1, 0x0a, 0xb7, 0xd5, 0x4b, 0xfe, 0xd3, 0xc9,
+    ///     0x64, 0x07, 0x3a, 0x0e, 0xe1, 0x72, 0xf3, 0xda, 0xa6, 0x23, 0x25, 0xaf, 0x02, 0x1a, 0x68,
+    ///     0xf7, 0x07, 0x51, 0x1a,
+    /// ];
+    /// let keypair = Keypair::from_ed25519_secret_key(ed25519_sk).unwrap();
+    /// assert_eq!(
+    ///     *keypair.public(),
+    ///     x25519_base(*keypair.secret())
+    /// );
+    /// ```
+    ///
+    pub fn from_ed25519_secret_key(sk: [u8; 64]) -> Result<Keypair, Error> {
+        let mut seed = [0u8; 32];
+        let mut ed25519_pk = [0u8; 32];
+        seed.copy_from_slice(&sk[..32]);
+        ed25519_pk.copy_from_slice(&sk[32..]);
+
+        Keypair::from_ed25519(seed, ed25519_pk)
+    }
+
+    /// The Curve25519 secret key.
+    pub fn secret(&self) -> &[u8; 32] {
+        &self.secret
+    }
+
+    /// The Curve25519 public key.
+    pub fn public(&self) -> &[u8; 32] {
+        &self.public
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Keypair {
+    fn drop(&mut self) {
+        self.secret.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl ZeroizeOnDrop for Keypair {}
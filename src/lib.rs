@@ -1,15 +1,58 @@
 //! Ed25519 keys can be converted to X25519 keys, so that the same key pair can be used both for authenticated
 //! encryption (crypto_box) and for signatures (crypto_sign).
+//!
+//! Functions that handle secret keys, and [`ed25519_pk_to_curve25519`], run in constant time: the
+//! field arithmetic has no secret-dependent branches or memory accesses, and selections use masks
+//! (from the `subtle` crate with the `subtle` feature). The `try_*` functions only branch on
+//! whether the input is valid. `tests/dudect.rs` contains timing tests for the conversions.
 #![no_std]
 #![allow(clippy::all)]
+#[cfg(feature = "std")]
+extern crate std;
+
+mod constants;
+mod ct;
+mod ed25519;
+mod edwards;
+mod error;
 mod field;
+// Both backends are compiled for tests, which check that they agree.
+#[cfg(any(
+    test,
+    feature = "u32_backend",
+    all(not(feature = "u64_backend"), not(target_pointer_width = "64"))
+))]
 mod field_element_2625;
-mod sha512;
+#[cfg(any(
+    test,
+    all(
+        not(feature = "u32_backend"),
+        any(feature = "u64_backend", target_pointer_width = "64")
+    )
+))]
+mod field_element_51;
+pub mod hkdf;
+pub mod hmac;
+mod keypair;
+mod montgomery;
+mod scalar;
+pub mod sha512;
+mod xeddsa;
 
+use ct::{Choice, ConditionallySelectable};
+use edwards::EdwardsPoint;
 use field::FieldElement;
 
+pub use error::Error;
+pub use keypair::Keypair;
+pub use scalar::Scalar;
+#[cfg(feature = "zeroize")]
+pub use zeroize::Zeroizing;
+
 /// Convert Ed25519 public key to Curve25519 public key.
 ///
+/// Runs in constant time.
+///
 /// # Example
 ///
 /// ```rust
@@ -44,8 +87,292 @@ pub fn ed25519_pk_to_curve25519(pk: [u8; 32]) -> [u8; 32] {
     x.to_bytes()
 }
 
+/// Convert many Ed25519 public keys to Curve25519 public keys at once.
+///
+/// Gives the same results as calling [`ed25519_pk_to_curve25519`] on each key, but shares a
+/// single field inversion across the whole batch (Montgomery's trick), which makes the conversion
+/// several times faster per key. As in the single conversion, a key with y = 1 converts to zero
+/// and does not affect the other keys. `out` is also used as scratch space.
+///
+/// # Panics
+///
+/// Panics if `pks` and `out` have different lengths.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::*;
+/// let ed25519_pk = [
+///     59, 106, 39, 188, 206, 182, 164, 45, 98, 163, 168, 208, 42, 111, 13, 115, 101, 50, 21, 119,
+///     29, 226, 67, 166, 58, 192, 72, 161, 139, 89, 218, 41,
+/// ];
+/// let curve25519_pk = [
+///     91, 245, 92, 115, 184, 46, 190, 34, 190, 128, 243, 67, 6, 103, 175, 87, 15, 174, 37, 86,
+///     166, 65, 94, 107, 48, 212, 6, 83, 0, 170, 148, 125,
+/// ];
+/// let pks = [ed25519_pk; 3];
+/// let mut out = [[0u8; 32]; 3];
+/// batch_ed25519_pk_to_curve25519(&pks, &mut out);
+/// assert_eq!(out, [curve25519_pk; 3])
+/// ```
+///
+pub fn batch_ed25519_pk_to_curve25519(pks: &[[u8; 32]], out: &mut [[u8; 32]]) {
+    assert_eq!(pks.len(), out.len());
+
+    let one = FieldElement::one();
+
+    // Return 1 - y, replaced by 1 if it is zero so that it does not
+    // zero the product of all denominators.
+    let denominator = |pk: &[u8; 32]| -> (FieldElement, FieldElement, Choice) {
+        let y = FieldElement::from_bytes(pk);
+        let one_minus_y = &one - &y;
+        let is_zero = one_minus_y.is_zero();
+
+        (
+            y,
+            FieldElement::conditional_select(&one_minus_y, &one, is_zero),
+            is_zero,
+        )
+    };
+
+    // Store the prefix products d_0 * ... * d_(i-1) in out[i].
+    let mut acc = FieldElement::one();
+    for (pk, scratch) in pks.iter().zip(out.iter_mut()) {
+        let (_, d, _) = denominator(pk);
+        *scratch = acc.to_bytes();
+        acc = &acc * &d;
+    }
+
+    // acc = 1/(d_0 * ... * d_(n-1)); walking backwards, multiplying by
+    // the prefix product gives 1/d_i and multiplying by d_i drops it.
+    acc = acc.invert();
+    for (pk, result) in pks.iter().zip(out.iter_mut()).rev() {
+        let (y, d, is_zero) = denominator(pk);
+        let prefix = FieldElement::from_bytes(result);
+        let d_inv = &acc * &prefix;
+        acc = &acc * &d;
+
+        let mut u = &(&one + &y) * &d_inv;
+        u.conditional_assign(&FieldElement::zero(), is_zero);
+
+        *result = u.to_bytes();
+    }
+}
+
+/// Options for [`try_ed25519_pk_to_curve25519_with_options`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConversionOptions {
+    /// Reject the eight small-order Ed25519 points (see [`is_small_order`]), and points with a
+    /// small-order component that are not in the prime-order subgroup.
+    ///
+    /// Enabled by default. A Curve25519 public key derived from one of these points lets the other
+    /// party force a known Diffie-Hellman shared secret, or learn the secret key modulo 8.
+    pub reject_small_order: bool,
+}
+
+impl Default for ConversionOptions {
+    fn default() -> ConversionOptions {
+        ConversionOptions {
+            reject_small_order: true,
+        }
+    }
+}
+
+/// Check whether an Ed25519 public key is one of the eight points of small order.
+///
+/// Returns `false` for byte strings that are not canonical encodings of a curve point.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::*;
+/// let ed25519_pk = [
+///     59, 106, 39, 188, 206, 182, 164, 45, 98, 163, 168, 208, 42, 111, 13, 115, 101, 50, 21, 119,
+///     29, 226, 67, 166, 58, 192, 72, 161, 139, 89, 218, 41,
+/// ];
+/// assert!(!is_small_order(ed25519_pk));
+///
+/// let mut identity = [0u8; 32];
+/// identity[0] = 1;
+/// assert!(is_small_order(identity));
+/// ```
+///
+pub fn is_small_order(pk: [u8; 32]) -> bool {
+    match EdwardsPoint::decompress(&pk) {
+        Ok(point) => point.is_small_order(),
+        Err(_) => false,
+    }
+}
+
+/// Convert Ed25519 public key to Curve25519 public key, rejecting invalid points.
+///
+/// Unlike [`ed25519_pk_to_curve25519`], the public key is fully decoded first, as libsodium's
+/// `crypto_sign_ed25519_pk_to_curve25519` does. The conversion fails if the encoding is not
+/// canonical, if it is not a point on the curve, if the sign bit is set for x = 0, if the point
+/// is the identity, if it has small order or if it is not in the prime-order subgroup. The running
+/// time only depends on which check fails; valid keys are converted in constant time.
+///
+/// This is [`try_ed25519_pk_to_curve25519_with_options`] with the default options.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::*;
+/// let ed25519_pk = [
+///     59, 106, 39, 188, 206, 182, 164, 45, 98, 163, 168, 208, 42, 111, 13, 115, 101, 50, 21, 119,
+///     29, 226, 67, 166, 58, 192, 72, 161, 139, 89, 218, 41,
+/// ];
+/// let curve25519_pk = [
+///     91, 245, 92, 115, 184, 46, 190, 34, 190, 128, 243, 67, 6, 103, 175, 87, 15, 174, 37, 86,
+///     166, 65, 94, 107, 48, 212, 6, 83, 0, 170, 148, 125,
+/// ];
+/// assert_eq!(try_ed25519_pk_to_curve25519(ed25519_pk), Ok(curve25519_pk));
+///
+/// let mut identity = [0u8; 32];
+/// identity[0] = 1;
+/// assert_eq!(try_ed25519_pk_to_curve25519(identity), Err(Error::Identity));
+/// ```
+///
+pub fn try_ed25519_pk_to_curve25519(pk: [u8; 32]) -> Result<[u8; 32], Error> {
+    try_ed25519_pk_to_curve25519_with_options(pk, ConversionOptions::default())
+}
+
+/// Convert Ed25519 public key to Curve25519 public key with the given options.
+///
+/// The identity is always rejected, since it has no Curve25519 equivalent.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::*;
+/// // y = -1, the point of order 2
+/// let mut ed25519_pk = [0xff; 32];
+/// ed25519_pk[0] = 0xec;
+/// ed25519_pk[31] = 0x7f;
+///
+/// assert_eq!(try_ed25519_pk_to_curve25519(ed25519_pk), Err(Error::SmallOrder));
+///
+/// let options = ConversionOptions {
+///     reject_small_order: false,
+/// };
+/// assert_eq!(
+///     try_ed25519_pk_to_curve25519_with_options(ed25519_pk, options),
+///     Ok([0u8; 32])
+/// );
+/// ```
+///
+pub fn try_ed25519_pk_to_curve25519_with_options(
+    pk: [u8; 32],
+    options: ConversionOptions,
+) -> Result<[u8; 32], Error> {
+    let point = EdwardsPoint::decompress(&pk)?;
+
+    if point.is_identity() {
+        return Err(Error::Identity);
+    }
+
+    if options.reject_small_order {
+        if point.is_small_order() {
+            return Err(Error::SmallOrder);
+        }
+        if !point.is_torsion_free() {
+            return Err(Error::NotPrimeOrder);
+        }
+    }
+
+    Ok(point.to_montgomery().to_bytes())
+}
+
+/// Convert Curve25519 public key to Ed25519 public key.
+///
+/// Computes y = (u - 1)/(u + 1) and stores `sign_bit` (0 or 1) as the sign of the x-coordinate, as
+/// `convert_mont` does in the XEdDSA specification. A Curve25519 public key only determines the
+/// Ed25519 public key up to its sign, so the sign bit has to be transmitted separately.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::*;
+/// let ed25519_pk = [
+///     59, 106, 39, 188, 206, 182, 164, 45, 98, 163, 168, 208, 42, 111, 13, 115, 101, 50, 21, 119,
+///     29, 226, 67, 166, 58, 192, 72, 161, 139, 89, 218, 41,
+/// ];
+/// let curve25519_pk = [
+///     91, 245, 92, 115, 184, 46, 190, 34, 190, 128, 243, 67, 6, 103, 175, 87, 15, 174, 37, 86,
+///     166, 65, 94, 107, 48, 212, 6, 83, 0, 170, 148, 125,
+/// ];
+/// assert_eq!(curve25519_pk_to_ed25519(curve25519_pk, 0), ed25519_pk)
+/// ```
+///
+pub fn curve25519_pk_to_ed25519(pk: [u8; 32], sign_bit: u8) -> [u8; 32] {
+    let u = FieldElement::from_bytes(&pk);
+
+    let mut u_plus_one = FieldElement::one();
+
+    u_plus_one = &u_plus_one + &u;
+
+    u_plus_one = u_plus_one.invert();
+
+    let mut y = FieldElement::one();
+
+    y = &u - &y;
+
+    y = &y * &u_plus_one;
+
+    let mut result = y.to_bytes();
+
+    result[31] |= (sign_bit & 1) << 7;
+
+    result
+}
+
+/// Convert Curve25519 public key to Ed25519 public key, rejecting invalid points.
+///
+/// The u-coordinate must be a canonical encoding: reduced modulo 2^255 - 19, with bit 255 clear.
+/// The result of [`curve25519_pk_to_ed25519`] is then decoded to check that it is a point on the
+/// Edwards curve. This rejects u-coordinates on the quadratic twist, u = -1 (which has no Edwards
+/// equivalent) and a sign bit of 1 when x = 0.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::*;
+/// let curve25519_pk = [
+///     91, 245, 92, 115, 184, 46, 190, 34, 190, 128, 243, 67, 6, 103, 175, 87, 15, 174, 37, 86,
+///     166, 65, 94, 107, 48, 212, 6, 83, 0, 170, 148, 125,
+/// ];
+/// assert_eq!(
+///     try_curve25519_pk_to_ed25519(curve25519_pk, 1),
+///     Ok(curve25519_pk_to_ed25519(curve25519_pk, 1))
+/// );
+///
+/// // u = 2 is on the twist
+/// let mut twist_pk = [0u8; 32];
+/// twist_pk[0] = 2;
+/// assert_eq!(try_curve25519_pk_to_ed25519(twist_pk, 0), Err(Error::NotOnCurve));
+/// ```
+///
+pub fn try_curve25519_pk_to_ed25519(pk: [u8; 32], sign_bit: u8) -> Result<[u8; 32], Error> {
+    let u = FieldElement::from_bytes(&pk);
+
+    if u.to_bytes() != pk {
+        return Err(Error::NonCanonicalEncoding);
+    }
+
+    if bool::from((&u + &FieldElement::one()).is_zero()) {
+        return Err(Error::NotOnCurve);
+    }
+
+    let result = curve25519_pk_to_ed25519(pk, sign_bit);
+    EdwardsPoint::decompress(&result)?;
+
+    Ok(result)
+}
+
 /// Convert Ed25519 secret key to Curve25519 secret key.
 ///
+/// Runs in constant time.
+///
 /// # Example
 ///
 /// ```rust
@@ -62,6 +389,9 @@ pub fn ed25519_pk_to_curve25519(pk: [u8; 32]) -> [u8; 32] {
 /// ```
 ///
 pub fn ed25519_sk_to_curve25519(sk: [u8; 32]) -> [u8; 32] {
+    #[cfg(feature = "zeroize")]
+    let mut h = sha512::sha512_zeroizing(&sk);
+    #[cfg(not(feature = "zeroize"))]
     let mut h = sha512::sha512(&sk);
 
     h[0] &= 248;
@@ -74,8 +404,179 @@ pub fn ed25519_sk_to_curve25519(sk: [u8; 32]) -> [u8; 32] {
     result
 }
 
+/// Convert Ed25519 secret key to Curve25519 secret key, wiping all secret intermediates.
+///
+/// The SHA-512 state and output are wiped, including the half of the hash that Ed25519 uses as
+/// the nonce prefix, and the result is wiped when it is dropped. Requires the `zeroize` feature.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::*;
+/// let ed25519_sk = [
+///     202, 104, 239, 81, 53, 110, 80, 252, 198, 23, 155, 162, 215, 98, 223, 173, 227, 188, 110,
+///     54, 127, 45, 185, 206, 174, 29, 44, 147, 76, 66, 196, 195,
+/// ];
+/// let curve25519_sk = ed25519_sk_to_curve25519_zeroizing(&ed25519_sk);
+/// assert_eq!(*curve25519_sk, ed25519_sk_to_curve25519(ed25519_sk));
+/// ```
+///
+#[cfg(feature = "zeroize")]
+pub fn ed25519_sk_to_curve25519_zeroizing(sk: &[u8; 32]) -> Zeroizing<[u8; 32]> {
+    let h = sha512::sha512_zeroizing(sk);
+
+    let mut result = Zeroizing::new([0u8; 32]);
+    result.copy_from_slice(&h[..32]);
+
+    result[0] &= 248;
+    result[31] &= 127;
+    result[31] |= 64;
+
+    result
+}
+
+/// Convert Ed25519 secret key to Curve25519 secret key, checking the key length.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::*;
+/// let ed25519_sk = [
+///     202, 104, 239, 81, 53, 110, 80, 252, 198, 23, 155, 162, 215, 98, 223, 173, 227, 188, 110,
+///     54, 127, 45, 185, 206, 174, 29, 44, 147, 76, 66, 196, 195,
+/// ];
+/// let curve25519_sk = [
+///     200, 255, 64, 61, 17, 52, 112, 33, 205, 71, 186, 13, 131, 12, 241, 136, 223, 5, 152, 40,
+///     95, 187, 83, 168, 142, 10, 234, 215, 70, 210, 148, 104,
+/// ];
+/// assert_eq!(try_ed25519_sk_to_curve25519(&ed25519_sk), Ok(curve25519_sk));
+/// assert_eq!(
+///     try_ed25519_sk_to_curve25519(&ed25519_sk[..31]),
+///     Err(Error::InvalidSecretKeyLength)
+/// );
+/// ```
+///
+pub fn try_ed25519_sk_to_curve25519(sk: &[u8]) -> Result<[u8; 32], Error> {
+    let mut seed = [0u8; 32];
+    if sk.len() != seed.len() {
+        return Err(Error::InvalidSecretKeyLength);
+    }
+    seed.copy_from_slice(sk);
+
+    Ok(ed25519_sk_to_curve25519(seed))
+}
+
+/// Convert an expanded Ed25519 secret key to Curve25519 secret key.
+///
+/// The expanded key is the 64-byte `scalar || prefix` that Ed25519 derives from the seed by hashing
+/// and clamping, as exported by HSMs or produced by BIP32-Ed25519 and key blinding. No seed is
+/// available for such keys, so the scalar is used directly instead of being hashed like in
+/// [`ed25519_sk_to_curve25519`]. The prefix is ignored.
+///
+/// The scalar is returned unchanged. X25519 clamps every secret key on use (clearing bits 0, 1, 2
+/// and 255 and setting bit 254), so the Curve25519 public key of the result only matches the
+/// converted Ed25519 public key if the scalar was already clamped. Derived and blinded scalars
+/// often are not; use [`try_ed25519_expanded_sk_to_curve25519`] to detect this.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::*;
+/// let mut expanded_sk = [0u8; 64];
+/// expanded_sk[..32].copy_from_slice(&[
+///     200, 255, 64, 61, 17, 52, 112, 33, 205, 71, 186, 13, 131, 12, 241, 136, 223, 5, 152, 40,
+///     95, 187, 83, 168, 142, 10, 234, 215, 70, 210, 148, 104,
+/// ]);
+/// let curve25519_sk = [
+///     200, 255, 64, 61, 17, 52, 112, 33, 205, 71, 186, 13, 131, 12, 241, 136, 223, 5, 152, 40,
+///     95, 187, 83, 168, 142, 10, 234, 215, 70, 210, 148, 104,
+/// ];
+/// assert_eq!(ed25519_expanded_sk_to_curve25519(expanded_sk), curve25519_sk)
+/// ```
+///
+pub fn ed25519_expanded_sk_to_curve25519(sk: [u8; 64]) -> [u8; 32] {
+    let mut result = [0u8; 32];
+    result.copy_from_slice(&sk[..32]);
+
+    result
+}
+
+/// Convert an expanded Ed25519 secret key to Curve25519 secret key, rejecting unclamped scalars.
+///
+/// Fails with [`Error::UnclampedScalar`] if the scalar is not in the clamped form that X25519 uses,
+/// because X25519 would then use a different scalar than the one the Ed25519 public key was
+/// computed with.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::*;
+/// let mut expanded_sk = [0u8; 64];
+/// expanded_sk[31] = 64;
+/// assert!(try_ed25519_expanded_sk_to_curve25519(expanded_sk).is_ok());
+///
+/// expanded_sk[0] = 1;
+/// assert_eq!(
+///     try_ed25519_expanded_sk_to_curve25519(expanded_sk),
+///     Err(Error::UnclampedScalar)
+/// );
+/// ```
+///
+pub fn try_ed25519_expanded_sk_to_curve25519(sk: [u8; 64]) -> Result<[u8; 32], Error> {
+    let result = ed25519_expanded_sk_to_curve25519(sk);
+
+    if montgomery::clamp_scalar(result) != result {
+        return Err(Error::UnclampedScalar);
+    }
+
+    Ok(result)
+}
+
+/// Derive Ed25519 public key from Ed25519 secret key (the 32-byte seed).
+///
+/// Runs in constant time.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::*;
+/// // RFC 8032, section 7.1, TEST 1
+/// let ed25519_sk = [
+///     0x9d, 0x61, 0xb1, 0x9d, 0xef, 0xfd, 0x5a, 0x60, 0xba, 0x84, 0x4a, 0xf4, 0x92, 0xec, 0x2c,
+///     0xc4, 0x44, 0x49, 0xc5, 0x69, 0x7b, 0x32, 0x69, 0x19, 0x70, 0x3b, 0xac, 0x03, 0x1c, 0xae,
+///     0x7f, 0x60,
+/// ];
+/// let ed25519_pk = [
+///     0xd7, 0x5a, 0x98, 0x01, 0x82, 0xb1, 0x0a, 0xb7, 0xd5, 0x4b, 0xfe, 0xd3, 0xc9, 0x64, 0x07,
+///     0x3a, 0x0e, 0xe1, 0x72, 0xf3, 0xda, 0xa6, 0x23, 0x25, 0xaf, 0x02, 0x1a, 0x68, 0xf7, 0x07,
+///     0x51, 0x1a,
+/// ];
+/// assert_eq!(ed25519_public_from_seed(ed25519_sk), ed25519_pk);
+/// assert_eq!(
+///     ed25519_pk_to_curve25519(ed25519_public_from_seed(ed25519_sk)),
+///     x25519_base(ed25519_sk_to_curve25519(ed25519_sk))
+/// );
+/// ```
+///
+pub fn ed25519_public_from_seed(seed: [u8; 32]) -> [u8; 32] {
+    #[cfg(feature = "zeroize")]
+    let scalar = ed25519_sk_to_curve25519_zeroizing(&seed);
+    #[cfg(not(feature = "zeroize"))]
+    let scalar = ed25519_sk_to_curve25519(seed);
+
+    EdwardsPoint::mul_base(&scalar).compress()
+}
+
 /// Convert Ed25519 sign to Curve25519 sign.
 ///
+/// The sign bit of the public key is stored in bit 255 of the signature, which is always zero in
+/// a valid Ed25519 signature since `s` is less than the group order. [`curve25519_sign_to_ed25519`]
+/// reverses the conversion.
+///
+/// Returns [`Error::InvalidScalar`] if bit 255 of `sign` is already set, since the sign bit could
+/// not be stored. [`try_ed25519_sign_to_curve25519`] also checks the public key and that `s` is
+/// fully reduced.
+///
 /// # Example
 ///
 /// ```rust
@@ -95,26 +596,360 @@ pub fn ed25519_sk_to_curve25519(sk: [u8; 32]) -> [u8; 32] {
 ///     138, 219, 26, 134, 231, 237, 187, 70, 163, 58, 141, 120, 77, 248, 226, 86, 102, 171, 130,
 ///     120, 95, 109, 87, 13, 12,
 /// ];
-/// assert_eq!(ed25519_sign_to_curve25519(ed25519_pk, ed25519_sign), curve25519_sign)
+/// assert_eq!(ed25519_sign_to_curve25519(ed25519_pk, ed25519_sign), Ok(curve25519_sign));
+///
+/// let mut invalid_sign = ed25519_sign;
+/// invalid_sign[63] |= 0x80;
+/// assert_eq!(
+///     ed25519_sign_to_curve25519(ed25519_pk, invalid_sign),
+///     Err(Error::InvalidScalar)
+/// );
 /// ```
 ///
-pub fn ed25519_sign_to_curve25519(pk: [u8; 32], sign: [u8; 64]) -> [u8; 64] {
+pub fn ed25519_sign_to_curve25519(pk: [u8; 32], sign: [u8; 64]) -> Result<[u8; 64], Error> {
+    if sign[63] & 0x80 != 0 {
+        return Err(Error::InvalidScalar);
+    }
+
     let mut result = sign;
 
     let sign_bit = pk[31] & 0x80;
 
     result[63] = result[63] | sign_bit;
 
-    result
+    Ok(result)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Convert Ed25519 sign to Curve25519 sign, validating the public key and signature.
+///
+/// The public key must decode to a curve point, the signature must be 64 bytes long and its `s`
+/// half must be reduced modulo the group order, which also guarantees that bit 255 is free for the
+/// sign bit.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::*;
+/// let ed25519_pk = [
+///     59, 106, 39, 188, 206, 182, 164, 45, 98, 163, 168, 208, 42, 111, 13, 115, 101, 50, 21, 119,
+///     29, 226, 67, 166, 58, 192, 72, 161, 139, 89, 218, 41,
+/// ];
+/// let ed25519_sign = [202, 104, 239, 81, 53, 110, 80, 252, 198, 23, 155, 162, 215, 98, 223, 173, 227, 188, 110,
+///     54, 127, 45, 185, 206, 174, 29, 44, 147, 76, 66, 196, 195, 53, 164, 40, 138, 28, 75, 103,
+///     138, 219, 26, 134, 231, 237, 187, 70, 163, 58, 141, 120, 77, 248, 226, 86, 102, 171, 130,
+///     120, 95, 109, 87, 13, 12,
+/// ];
+/// assert_eq!(
+///     try_ed25519_sign_to_curve25519(ed25519_pk, &ed25519_sign),
+///     ed25519_sign_to_curve25519(ed25519_pk, ed25519_sign)
+/// );
+/// assert_eq!(
+///     try_ed25519_sign_to_curve25519(ed25519_pk, &ed25519_sign[..32]),
+///     Err(Error::InvalidSignatureLength)
+/// );
+/// ```
+///
+pub fn try_ed25519_sign_to_curve25519(pk: [u8; 32], sign: &[u8]) -> Result<[u8; 64], Error> {
+    EdwardsPoint::decompress(&pk)?;
 
-    const ED25519_PK: [u8; 32] = [
-        59, 106, 39, 188, 206, 182, 164, 45, 98, 163, 168, 208, 42, 111, 13, 115, 101, 50, 21, 119,
-        29, 226, 67, 166, 58, 192, 72, 161, 139, 89, 218, 41,
+    let mut signature = [0u8; 64];
+    if sign.len() != signature.len() {
+        return Err(Error::InvalidSignatureLength);
+    }
+    signature.copy_from_slice(sign);
+
+    let mut s = [0u8; 32];
+    s.copy_from_slice(&signature[32..]);
+    Scalar::from_canonical_bytes(s)?;
+
+    ed25519_sign_to_curve25519(pk, signature)
+}
+
+/// Convert Curve25519 sign to Ed25519 sign.
+///
+/// Clears bit 255 of the signature and returns it as the sign bit (0 or 1) of the Ed25519 public
+/// key, to be passed to [`curve25519_pk_to_ed25519`]. This reverses [`ed25519_sign_to_curve25519`].
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::*;
+/// let ed25519_pk = [
+///     59, 106, 39, 188, 206, 182, 164, 45, 98, 163, 168, 208, 42, 111, 13, 115, 101, 50, 21, 119,
+///     29, 226, 67, 166, 58, 192, 72, 161, 139, 89, 218, 41,
+/// ];
+/// let ed25519_sign = [202, 104, 239, 81, 53, 110, 80, 252, 198, 23, 155, 162, 215, 98, 223, 173, 227, 188, 110,
+///     54, 127, 45, 185, 206, 174, 29, 44, 147, 76, 66, 196, 195, 53, 164, 40, 138, 28, 75, 103,
+///     138, 219, 26, 134, 231, 237, 187, 70, 163, 58, 141, 120, 77, 248, 226, 86, 102, 171, 130,
+///     120, 95, 109, 87, 13, 12,
+/// ];
+/// let curve25519_sign = ed25519_sign_to_curve25519(ed25519_pk, ed25519_sign).unwrap();
+/// assert_eq!(curve25519_sign_to_ed25519(curve25519_sign), (ed25519_sign, 0));
+/// ```
+///
+pub fn curve25519_sign_to_ed25519(sign: [u8; 64]) -> ([u8; 64], u8) {
+    let mut result = sign;
+
+    let sign_bit = result[63] >> 7;
+
+    result[63] &= 0x7f;
+
+    (result, sign_bit)
+}
+
+/// Compute the X25519 function from RFC 7748: multiply the Curve25519 point with u-coordinate `u`
+/// by the clamped scalar.
+///
+/// The computation runs in constant time with respect to `scalar`. The result is all zeros if `u`
+/// is a point of small order.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::*;
+/// let ed25519_sk = [
+///     202, 104, 239, 81, 53, 110, 80, 252, 198, 23, 155, 162, 215, 98, 223, 173, 227, 188, 110,
+///     54, 127, 45, 185, 206, 174, 29, 44, 147, 76, 66, 196, 195,
+/// ];
+/// let peer_sk = [7u8; 32];
+///
+/// let curve25519_sk = ed25519_sk_to_curve25519(ed25519_sk);
+/// let shared = x25519(curve25519_sk, x25519_base(peer_sk));
+/// assert_eq!(shared, x25519(peer_sk, x25519_base(curve25519_sk)));
+/// ```
+///
+pub fn x25519(scalar: [u8; 32], u: [u8; 32]) -> [u8; 32] {
+    let k = montgomery::clamp_scalar(scalar);
+    let u = FieldElement::from_bytes(&u);
+
+    montgomery::mul(&k, &u).to_bytes()
+}
+
+/// Compute the X25519 public key for a secret scalar, i.e. [`x25519`] with the base point u = 9.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::*;
+/// // RFC 7748, section 6.1
+/// let curve25519_sk = [
+///     0x77, 0x07, 0x6d, 0x0a, 0x73, 0x18, 0xa5, 0x7d, 0x3c, 0x16, 0xc1, 0x72, 0x51, 0xb2, 0x66,
+///     0x45, 0xdf, 0x4c, 0x2f, 0x87, 0xeb, 0xc0, 0x99, 0x2a, 0xb1, 0x77, 0xfb, 0xa5, 0x1d, 0xb9,
+///     0x2c, 0x2a,
+/// ];
+/// let curve25519_pk = [
+///     0x85, 0x20, 0xf0, 0x09, 0x89, 0x30, 0xa7, 0x54, 0x74, 0x8b, 0x7d, 0xdc, 0xb4, 0x3e, 0xf7,
+///     0x5a, 0x0d, 0xbf, 0x3a, 0x0d, 0x26, 0x38, 0x1a, 0xf4, 0xeb, 0xa4, 0xa9, 0x8e, 0xaa, 0x9b,
+///     0x4e, 0x6a,
+/// ];
+/// assert_eq!(x25519_base(curve25519_sk), curve25519_pk)
+/// ```
+///
+pub fn x25519_base(scalar: [u8; 32]) -> [u8; 32] {
+    let mut basepoint = [0u8; 32];
+    basepoint[0] = 9;
+
+    x25519(scalar, basepoint)
+}
+
+/// Sign a message with an Ed25519 secret key, as in RFC 8032, section 5.1.6.
+///
+/// Signing is deterministic and runs in constant time with respect to the secret key. The
+/// matching public key is [`ed25519_public_from_seed`]`(sk)`.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::*;
+/// let ed25519_sk = [7u8; 32];
+/// let ed25519_pk = ed25519_public_from_seed(ed25519_sk);
+/// let signature = ed25519_sign(ed25519_sk, b"message");
+/// assert_eq!(ed25519_verify(ed25519_pk, b"message", &signature), Ok(()));
+///
+/// // The same key pair, converted, for X25519
+/// let shared = x25519(ed25519_sk_to_curve25519(ed25519_sk), x25519_base([9u8; 32]));
+/// assert_eq!(shared, x25519([9u8; 32], ed25519_pk_to_curve25519(ed25519_pk)));
+/// ```
+///
+pub fn ed25519_sign(sk: [u8; 32], msg: &[u8]) -> [u8; 64] {
+    ed25519::sign(&sk, msg)
+}
+
+/// Verify an Ed25519 signature, as in RFC 8032, section 5.1.7.
+///
+/// The public key and R must be canonical encodings of curve points and s must be reduced modulo
+/// the group order. The check uses the cofactorless equation `[s]B = R + [k]A`.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::*;
+/// let ed25519_sk = [7u8; 32];
+/// let ed25519_pk = ed25519_public_from_seed(ed25519_sk);
+/// let signature = ed25519_sign(ed25519_sk, b"message");
+/// assert_eq!(
+///     ed25519_verify(ed25519_pk, b"other message", &signature),
+///     Err(Error::InvalidSignature)
+/// );
+/// ```
+///
+#[allow(non_snake_case)]
+pub fn ed25519_verify(pk: [u8; 32], msg: &[u8], sig: &[u8]) -> Result<(), Error> {
+    let (R, s) = split_signature(sig)?;
+
+    ed25519::verify(&pk, msg, &R, &s, false)
+}
+
+/// Verify an Ed25519 signature, also rejecting public keys and R of small order.
+///
+/// With a small-order public key, one signature can be valid for several messages. Use this when
+/// the public key is not trusted.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::*;
+/// let ed25519_sk = [7u8; 32];
+/// let ed25519_pk = ed25519_public_from_seed(ed25519_sk);
+/// let signature = ed25519_sign(ed25519_sk, b"message");
+/// assert_eq!(ed25519_verify_strict(ed25519_pk, b"message", &signature), Ok(()));
+/// ```
+///
+#[allow(non_snake_case)]
+pub fn ed25519_verify_strict(pk: [u8; 32], msg: &[u8], sig: &[u8]) -> Result<(), Error> {
+    let (R, s) = split_signature(sig)?;
+
+    ed25519::verify(&pk, msg, &R, &s, true)
+}
+
+/// Split a signature into R and s, checking its length and that s is reduced.
+#[allow(non_snake_case)]
+fn split_signature(sig: &[u8]) -> Result<([u8; 32], [u8; 32]), Error> {
+    if sig.len() != 64 {
+        return Err(Error::InvalidSignatureLength);
+    }
+
+    let mut R = [0u8; 32];
+    let mut s = [0u8; 32];
+    R.copy_from_slice(&sig[..32]);
+    s.copy_from_slice(&sig[32..]);
+
+    Scalar::from_canonical_bytes(s)?;
+
+    Ok((R, s))
+}
+
+/// Sign a message with an X25519 secret key, as in Signal's XEdDSA.
+///
+/// `random` must be 64 bytes from a secure random source, fresh for every
+/// signature. The result is a standard Ed25519 signature under the public
+/// key `curve25519_pk_to_ed25519(x25519_base(x25519_sk), 0)`.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::*;
+/// let x25519_sk = [7u8; 32];
+/// let random = [42u8; 64];
+/// let signature = xeddsa_sign(x25519_sk, b"message", random);
+///
+/// let x25519_pk = x25519_base(x25519_sk);
+/// assert_eq!(xeddsa_verify(x25519_pk, b"message", &signature), Ok(()));
+///
+/// let ed25519_pk = curve25519_pk_to_ed25519(x25519_pk, 0);
+/// assert_eq!(ed25519_verify(ed25519_pk, b"message", &signature), Ok(()));
+/// ```
+///
+pub fn xeddsa_sign(x25519_sk: [u8; 32], msg: &[u8], random: [u8; 64]) -> [u8; 64] {
+    xeddsa::sign(&x25519_sk, msg, &random)
+}
+
+/// Verify an XEdDSA signature with an X25519 public key.
+///
+/// The u-coordinate is converted to an Ed25519 public key with sign bit 0. Non-canonical encodings
+/// of u and R, and values of s that are not reduced modulo the group order, are rejected.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::*;
+/// let x25519_sk = [7u8; 32];
+/// let x25519_pk = x25519_base(x25519_sk);
+/// let signature = xeddsa_sign(x25519_sk, b"message", [42u8; 64]);
+/// assert_eq!(xeddsa_verify(x25519_pk, b"message", &signature), Ok(()));
+/// assert_eq!(
+///     xeddsa_verify(x25519_pk, b"other message", &signature),
+///     Err(Error::InvalidSignature)
+/// );
+/// ```
+///
+#[allow(non_snake_case)]
+pub fn xeddsa_verify(x25519_pk: [u8; 32], msg: &[u8], sig: &[u8]) -> Result<(), Error> {
+    let (R, s) = split_signature(sig)?;
+
+    xeddsa::verify(&x25519_pk, msg, &R, &s)
+}
+
+/// Sign a message with an X25519 secret key using VXEdDSA, and compute the VRF output.
+///
+/// Returns the 96-byte signature and the 32-byte VRF output. The output depends only on the key
+/// and the message, not on `random`, which must be 64 bytes from a secure random source.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::*;
+/// let x25519_sk = [7u8; 32];
+/// let (signature, output) = vxeddsa_sign(x25519_sk, b"message", [42u8; 64]);
+/// let (_, same_output) = vxeddsa_sign(x25519_sk, b"message", [43u8; 64]);
+/// assert_eq!(output, same_output);
+/// ```
+///
+pub fn vxeddsa_sign(x25519_sk: [u8; 32], msg: &[u8], random: [u8; 64]) -> ([u8; 96], [u8; 32]) {
+    xeddsa::vxeddsa_sign(&x25519_sk, msg, &random)
+}
+
+/// Verify a VXEdDSA signature with an X25519 public key, and return the VRF output.
+///
+/// Non-canonical encodings of u and V, values of h and s that are not reduced modulo the group
+/// order, and public keys or V of small order are rejected.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::*;
+/// let x25519_sk = [7u8; 32];
+/// let x25519_pk = x25519_base(x25519_sk);
+/// let (signature, output) = vxeddsa_sign(x25519_sk, b"message", [42u8; 64]);
+/// assert_eq!(vxeddsa_verify(x25519_pk, b"message", &signature), Ok(output));
+/// ```
+///
+#[allow(non_snake_case)]
+pub fn vxeddsa_verify(x25519_pk: [u8; 32], msg: &[u8], sig: &[u8]) -> Result<[u8; 32], Error> {
+    if sig.len() != 96 {
+        return Err(Error::InvalidSignatureLength);
+    }
+
+    let mut V = [0u8; 32];
+    let mut h = [0u8; 32];
+    let mut s = [0u8; 32];
+    V.copy_from_slice(&sig[..32]);
+    h.copy_from_slice(&sig[32..64]);
+    s.copy_from_slice(&sig[64..]);
+
+    Scalar::from_canonical_bytes(h)?;
+    Scalar::from_canonical_bytes(s)?;
+
+    xeddsa::vxeddsa_verify(&x25519_pk, msg, &V, &h, &s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ED25519_PK: [u8; 32] = [
+        59, 106, 39, 188, 206, 182, 164, 45, 98, 163, 168, 208, 42, 111, 13, 115, 101, 50, 21, 119,
+        29, 226, 67, 166, 58, 192, 72, 161, 139, 89, 218, 41,
     ];
     const CURVE25519_PK: [u8; 32] = [
         91, 245, 92, 115, 184, 46, 190, 34, 190, 128, 243, 67, 6, 103, 175, 87, 15, 174, 37, 86,
@@ -143,21 +978,1248 @@ mod tests {
         120, 95, 109, 87, 13, 12,
     ];
 
+    // RFC 8032, section 7.1, TEST 1
+    const RFC8032_SK: [u8; 32] = [
+        0x9d, 0x61, 0xb1, 0x9d, 0xef, 0xfd, 0x5a, 0x60, 0xba, 0x84, 0x4a, 0xf4, 0x92, 0xec, 0x2c,
+        0xc4, 0x44, 0x49, 0xc5, 0x69, 0x7b, 0x32, 0x69, 0x19, 0x70, 0x3b, 0xac, 0x03, 0x1c, 0xae,
+        0x7f, 0x60,
+    ];
+    const RFC8032_PK: [u8; 32] = [
+        0xd7, 0x5a, 0x98, 0x01, 0x82, 0xb1, 0x0a, 0xb7, 0xd5, 0x4b, 0xfe, 0xd3, 0xc9, 0x64, 0x07,
+        0x3a, 0x0e, 0xe1, 0x72, 0xf3, 0xda, 0xa6, 0x23, 0x25, 0xaf, 0x02, 0x1a, 0x68, 0xf7, 0x07,
+        0x51, 0x1a,
+    ];
+    const RFC8032_SIG: [u8; 64] = [
+        0xe5, 0x56, 0x43, 0x00, 0xc3, 0x60, 0xac, 0x72, 0x90, 0x86, 0xe2, 0xcc, 0x80, 0x6e, 0x82,
+        0x8a, 0x84, 0x87, 0x7f, 0x1e, 0xb8, 0xe5, 0xd9, 0x74, 0xd8, 0x73, 0xe0, 0x65, 0x22, 0x49,
+        0x01, 0x55, 0x5f, 0xb8, 0x82, 0x15, 0x90, 0xa3, 0x3b, 0xac, 0xc6, 0x1e, 0x39, 0x70, 0x1c,
+        0xf9, 0xb4, 0x6b, 0xd2, 0x5b, 0xf5, 0xf0, 0x59, 0x5b, 0xbe, 0x24, 0x65, 0x51, 0x41, 0x43,
+        0x8e, 0x7a, 0x10, 0x0b,
+    ];
+
     #[test]
     fn test_ed25519_pk_to_curve25519() {
         assert_eq!(ed25519_pk_to_curve25519(ED25519_PK), CURVE25519_PK);
     }
 
+    #[test]
+    fn test_try_ed25519_pk_to_curve25519() {
+        assert_eq!(try_ed25519_pk_to_curve25519(ED25519_PK), Ok(CURVE25519_PK));
+    }
+
+    #[test]
+    fn test_try_ed25519_pk_to_curve25519_invalid() {
+        // y = 1 is the identity
+        let mut pk = [0u8; 32];
+        pk[0] = 1;
+        assert_eq!(try_ed25519_pk_to_curve25519(pk), Err(Error::Identity));
+
+        // y = 1 with the sign bit set encodes x = -0
+        pk[31] = 0x80;
+        assert_eq!(try_ed25519_pk_to_curve25519(pk), Err(Error::InvalidSignBit));
+
+        // y = 2 has no matching x
+        let mut pk = [0u8; 32];
+        pk[0] = 2;
+        assert_eq!(try_ed25519_pk_to_curve25519(pk), Err(Error::NotOnCurve));
+
+        // y = p is a non-canonical encoding of y = 0
+        let mut pk = [0xff; 32];
+        pk[0] = 0xed;
+        pk[31] = 0x7f;
+        assert_eq!(
+            try_ed25519_pk_to_curve25519(pk),
+            Err(Error::NonCanonicalEncoding)
+        );
+    }
+
+    #[test]
+    fn test_try_ed25519_pk_to_curve25519_mixed_order() {
+        // [12345]B plus a point of order 8: on the curve and not of small order, but outside the
+        // prime-order subgroup
+        let pk = from_hex::<32>("cdbc161be64c4302f56443ff29ccf36a2075be1a1fa67ff126b56315f5681c38");
+        assert!(!is_small_order(pk));
+        assert_eq!(try_ed25519_pk_to_curve25519(pk), Err(Error::NotPrimeOrder));
+
+        // The plain conversion and the permissive options still convert it
+        let u = from_hex::<32>("ff7b26fbc2d43c38c1bec75c17e5bc294dff5895e1d4d3f567412cf07155f74a");
+        assert_eq!(ed25519_pk_to_curve25519(pk), u);
+        let permissive = ConversionOptions {
+            reject_small_order: false,
+        };
+        assert_eq!(
+            try_ed25519_pk_to_curve25519_with_options(pk, permissive),
+            Ok(u)
+        );
+    }
+
+    #[test]
+    fn test_field_backends_agree() {
+        use field_element_2625::FieldElement2625;
+        use field_element_51::FieldElement51;
+
+        let mut inputs = [[0u8; 32]; 8];
+        // 0, 1, p - 1, p and 2^255 - 1 (the last two are non-canonical)
+        inputs[1][0] = 1;
+        inputs[2] = [0xff; 32];
+        inputs[2][0] = 0xec;
+        inputs[2][31] = 0x7f;
+        inputs[3] = inputs[2];
+        inputs[3][0] = 0xed;
+        inputs[4] = [0xff; 32];
+        // and some arbitrary values
+        inputs[5] = ED25519_PK;
+        inputs[6] = ED25519_SK;
+        inputs[7] = RFC8032_PK;
+
+        for x in inputs.iter() {
+            let x32 = FieldElement2625::from_bytes(x);
+            let x64 = FieldElement51::from_bytes(x);
+            assert_eq!(x32.to_bytes(), x64.to_bytes());
+            assert_eq!((-&x32).to_bytes(), (-&x64).to_bytes());
+            assert_eq!(x32.square().to_bytes(), x64.square().to_bytes());
+            assert_eq!(x32.pow2k(5).to_bytes(), x64.pow2k(5).to_bytes());
+
+            for y in inputs.iter() {
+                let y32 = FieldElement2625::from_bytes(y);
+                let y64 = FieldElement51::from_bytes(y);
+                assert_eq!((&x32 + &y32).to_bytes(), (&x64 + &y64).to_bytes());
+                assert_eq!((&x32 - &y32).to_bytes(), (&x64 - &y64).to_bytes());
+                assert_eq!((&x32 * &y32).to_bytes(), (&x64 * &y64).to_bytes());
+
+                for choice in 0..2 {
+                    let choice = Choice::from(choice);
+                    assert_eq!(
+                        FieldElement2625::conditional_select(&x32, &y32, choice).to_bytes(),
+                        FieldElement51::conditional_select(&x64, &y64, choice).to_bytes()
+                    );
+                }
+            }
+        }
+    }
+
+    // Adapted from curve25519-dalek's `sqrt_ratio_i` test; see the licence
+    // notice at the top of src/field.rs.
+    #[test]
+    fn test_sqrt_ratio_i() {
+        let zero = FieldElement::zero();
+        let one = FieldElement::one();
+        let i = FieldElement::from_bytes(&constants::SQRT_M1);
+        let two = &one + &one;
+        let four = &two + &two;
+
+        // 0/0 should return (1, 0) since u is 0
+        let (choice, sqrt) = FieldElement::sqrt_ratio_i(&zero, &zero);
+        assert!(bool::from(choice));
+        assert!(sqrt == zero);
+
+        // 1/0 should return (0, 0) since v is 0, u is nonzero
+        let (choice, sqrt) = FieldElement::sqrt_ratio_i(&one, &zero);
+        assert!(!bool::from(choice));
+        assert!(sqrt == zero);
+
+        // 2/1 is nonsquare, so we expect (0, sqrt(i*2))
+        let (choice, sqrt) = FieldElement::sqrt_ratio_i(&two, &one);
+        assert!(!bool::from(choice));
+        assert!(sqrt.square() == &two * &i);
+        assert!(!bool::from(sqrt.is_negative()));
+
+        // 4 is square, so we expect (1, sqrt(4)) = (1, 2)
+        let (choice, sqrt) = FieldElement::sqrt_ratio_i(&four, &one);
+        assert!(bool::from(choice));
+        assert!(sqrt == two);
+
+        // 1/4 is square, so we expect (1, 1/2)
+        let (choice, sqrt) = FieldElement::sqrt_ratio_i(&one, &four);
+        assert!(bool::from(choice));
+        assert!(&sqrt.square() * &four == one);
+        assert!(!bool::from(sqrt.is_negative()));
+
+        // i^2 = -1
+        assert!(i.square() == -&one);
+    }
+
+    #[test]
+    fn test_is_square() {
+        let mut bytes = [0u8; 32];
+        for (n, expected) in [
+            (0, true),
+            (1, true),
+            (2, false),
+            (3, true),
+            (4, true),
+            (5, true),
+            (7, false),
+        ]
+        .iter()
+        {
+            bytes[0] = *n;
+            let x = FieldElement::from_bytes(&bytes);
+            assert_eq!(bool::from(x.is_square()), *expected, "{}", n);
+        }
+
+        // p = 1 (mod 4), so -1 is a square
+        assert!(bool::from((-&FieldElement::one()).is_square()));
+
+        // The Montgomery coefficient A = 486662 is not a square
+        let a = FieldElement::from_bytes(&[
+            0x06, 0x6d, 0x07, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ]);
+        assert!(!bool::from(a.is_square()));
+    }
+
+    #[test]
+    fn test_constant_time_primitives() {
+        use ct::ConstantTimeEq;
+
+        let one = FieldElement::one();
+        let mut a = FieldElement::zero();
+        let mut b = one;
+
+        assert!(bool::from(a.ct_eq(&a)));
+        assert!(!bool::from(a.ct_eq(&b)));
+        assert!(bool::from(a.is_zero()));
+        // 1 is odd, so "negative", and p - 1 is even
+        assert!(bool::from(one.is_negative()));
+        assert!(!bool::from((-&one).is_negative()));
+
+        assert!(FieldElement::conditional_select(&a, &b, Choice::from(0)) == a);
+        assert!(FieldElement::conditional_select(&a, &b, Choice::from(1)) == b);
+
+        FieldElement::conditional_swap(&mut a, &mut b, Choice::from(0));
+        assert!(bool::from(a.is_zero()));
+        FieldElement::conditional_swap(&mut a, &mut b, Choice::from(1));
+        assert!(a == one && bool::from(b.is_zero()));
+
+        a.conditional_negate(Choice::from(1));
+        assert!(a == -&one);
+    }
+
+    #[test]
+    fn test_batch_ed25519_pk_to_curve25519() {
+        let mut y_one = [0u8; 32];
+        y_one[0] = 1;
+
+        let pks = [ED25519_PK, RFC8032_PK, y_one, [0u8; 32], ED25519_SK, y_one];
+        let mut out = [[0xaa; 32]; 6];
+        batch_ed25519_pk_to_curve25519(&pks, &mut out);
+
+        for (pk, u) in pks.iter().zip(out.iter()) {
+            assert_eq!(*u, ed25519_pk_to_curve25519(*pk));
+        }
+        assert_eq!(out[2], [0u8; 32]);
+
+        batch_ed25519_pk_to_curve25519(&[], &mut []);
+        batch_ed25519_pk_to_curve25519(&[y_one], &mut out[..1]);
+        assert_eq!(out[0], [0u8; 32]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_batch_ed25519_pk_to_curve25519_length_mismatch() {
+        let mut out = [[0u8; 32]; 1];
+        batch_ed25519_pk_to_curve25519(&[ED25519_PK; 2], &mut out);
+    }
+
+    #[test]
+    fn test_is_small_order() {
+        // The canonical encodings of the eight points of E[8].
+        let mut small_order = [[0u8; 32]; 8];
+        // (0, 1) and (0, -1)
+        small_order[0][0] = 1;
+        small_order[1] = [0xff; 32];
+        small_order[1][0] = 0xec;
+        small_order[1][31] = 0x7f;
+        // (±sqrt(-1), 0)
+        small_order[3][31] = 0x80;
+        // The four points of order 8
+        small_order[4] = [
+            0x26, 0xe8, 0x95, 0x8f, 0xc2, 0xb2, 0x27, 0xb0, 0x45, 0xc3, 0xf4, 0x89, 0xf2, 0xef,
+            0x98, 0xf0, 0xd5, 0xdf, 0xac, 0x05, 0xd3, 0xc6, 0x33, 0x39, 0xb1, 0x38, 0x02, 0x88,
+            0x6d, 0x53, 0xfc, 0x05,
+        ];
+        small_order[5] = small_order[4];
+        small_order[5][31] |= 0x80;
+        small_order[6] = [
+            0xc7, 0x17, 0x6a, 0x70, 0x3d, 0x4d, 0xd8, 0x4f, 0xba, 0x3c, 0x0b, 0x76, 0x0d, 0x10,
+            0x67, 0x0f, 0x2a, 0x20, 0x53, 0xfa, 0x2c, 0x39, 0xcc, 0xc6, 0x4e, 0xc7, 0xfd, 0x77,
+            0x92, 0xac, 0x03, 0x7a,
+        ];
+        small_order[7] = small_order[6];
+        small_order[7][31] |= 0x80;
+
+        let permissive = ConversionOptions {
+            reject_small_order: false,
+        };
+        for pk in small_order.iter() {
+            assert!(is_small_order(*pk));
+            if *pk != small_order[0] {
+                assert_eq!(try_ed25519_pk_to_curve25519(*pk), Err(Error::SmallOrder));
+                assert!(try_ed25519_pk_to_curve25519_with_options(*pk, permissive).is_ok());
+            }
+        }
+
+        assert!(!is_small_order(ED25519_PK));
+    }
+
+    #[test]
+    fn test_curve25519_pk_to_ed25519() {
+        assert_eq!(curve25519_pk_to_ed25519(CURVE25519_PK, 0), ED25519_PK);
+
+        // Public key from RFC 8032, section 7.1, TEST 2
+        let ed25519_pk = [
+            0x3d, 0x40, 0x17, 0xc3, 0xe8, 0x43, 0x89, 0x5a, 0x92, 0xb7, 0x0a, 0xa7, 0x4d, 0x1b,
+            0x7e, 0xbc, 0x9c, 0x98, 0x2c, 0xcf, 0x2e, 0xc4, 0x96, 0x8c, 0xc0, 0xcd, 0x55, 0xf1,
+            0x2a, 0xf4, 0x66, 0x0c,
+        ];
+        for pk in [ED25519_PK, ed25519_pk].iter() {
+            for sign_bit in 0..2 {
+                let mut pk = *pk;
+                pk[31] |= sign_bit << 7;
+
+                let curve25519_pk = ed25519_pk_to_curve25519(pk);
+                assert_eq!(curve25519_pk_to_ed25519(curve25519_pk, sign_bit), pk);
+                assert_eq!(
+                    try_curve25519_pk_to_ed25519(curve25519_pk, sign_bit),
+                    Ok(pk)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_try_curve25519_pk_to_ed25519_invalid() {
+        // u = -1
+        let mut pk = [0xff; 32];
+        pk[0] = 0xec;
+        pk[31] = 0x7f;
+        assert_eq!(try_curve25519_pk_to_ed25519(pk, 0), Err(Error::NotOnCurve));
+
+        // u = 0 maps to (0, -1), which has no negative x
+        assert_eq!(try_curve25519_pk_to_ed25519([0u8; 32], 0), Ok(pk));
+        assert_eq!(
+            try_curve25519_pk_to_ed25519([0u8; 32], 1),
+            Err(Error::InvalidSignBit)
+        );
+
+        // u = p + 1 and u = 1 with bit 255 set are non-canonical encodings of u = 1
+        pk[0] = 0xee;
+        assert_eq!(
+            try_curve25519_pk_to_ed25519(pk, 0),
+            Err(Error::NonCanonicalEncoding)
+        );
+        let mut pk = [0u8; 32];
+        pk[0] = 1;
+        assert!(try_curve25519_pk_to_ed25519(pk, 0).is_ok());
+        pk[31] = 0x80;
+        assert_eq!(
+            try_curve25519_pk_to_ed25519(pk, 0),
+            Err(Error::NonCanonicalEncoding)
+        );
+    }
+
     #[test]
     fn test_ed25519_sk_to_curve25519() {
         assert_eq!(ed25519_sk_to_curve25519(ED25519_SK), CURVE25519_SK);
     }
 
+    #[test]
+    #[cfg(feature = "zeroize")]
+    fn test_ed25519_sk_to_curve25519_zeroizing() {
+        assert_eq!(
+            *ed25519_sk_to_curve25519_zeroizing(&ED25519_SK),
+            CURVE25519_SK
+        );
+
+        // Cover inputs that need one and two padding blocks.
+        let input = [0xa5u8; 300];
+        for len in 0..input.len() {
+            assert_eq!(
+                *sha512::sha512_zeroizing(&input[..len]),
+                sha512::sha512(&input[..len])
+            );
+        }
+    }
+
+    #[test]
+    fn test_try_ed25519_sk_to_curve25519() {
+        assert_eq!(try_ed25519_sk_to_curve25519(&ED25519_SK), Ok(CURVE25519_SK));
+        assert_eq!(
+            try_ed25519_sk_to_curve25519(&[0u8; 64]),
+            Err(Error::InvalidSecretKeyLength)
+        );
+    }
+
+    #[test]
+    fn test_ed25519_public_from_seed() {
+        // RFC 8032, section 7.1, TEST 1, 2 and 3
+        let vectors = [
+            (RFC8032_SK, RFC8032_PK),
+            (
+                [
+                    0x4c, 0xcd, 0x08, 0x9b, 0x28, 0xff, 0x96, 0xda, 0x9d, 0xb6, 0xc3, 0x46, 0xec,
+                    0x11, 0x4e, 0x0f, 0x5b, 0x8a, 0x31, 0x9f, 0x35, 0xab, 0xa6, 0x24, 0xda, 0x8c,
+                    0xf6, 0xed, 0x4f, 0xb8, 0xa6, 0xfb,
+                ],
+                [
+                    0x3d, 0x40, 0x17, 0xc3, 0xe8, 0x43, 0x89, 0x5a, 0x92, 0xb7, 0x0a, 0xa7, 0x4d,
+                    0x1b, 0x7e, 0xbc, 0x9c, 0x98, 0x2c, 0xcf, 0x2e, 0xc4, 0x96, 0x8c, 0xc0, 0xcd,
+                    0x55, 0xf1, 0x2a, 0xf4, 0x66, 0x0c,
+                ],
+            ),
+            (
+                [
+                    0xc5, 0xaa, 0x8d, 0xf4, 0x3f, 0x9f, 0x83, 0x7b, 0xed, 0xb7, 0x44, 0x2f, 0x31,
+                    0xdc, 0xb7, 0xb1, 0x66, 0xd3, 0x85, 0x35, 0x07, 0x6f, 0x09, 0x4b, 0x85, 0xce,
+                    0x3a, 0x2e, 0x0b, 0x44, 0x58, 0xf7,
+                ],
+                [
+                    0xfc, 0x51, 0xcd, 0x8e, 0x62, 0x18, 0xa1, 0xa3, 0x8d, 0xa4, 0x7e, 0xd0, 0x02,
+                    0x30, 0xf0, 0x58, 0x08, 0x16, 0xed, 0x13, 0xba, 0x33, 0x03, 0xac, 0x5d, 0xeb,
+                    0x91, 0x15, 0x48, 0x90, 0x80, 0x25,
+                ],
+            ),
+        ];
+        for (sk, pk) in vectors.iter() {
+            assert_eq!(ed25519_public_from_seed(*sk), *pk);
+            assert_eq!(EdwardsPoint::decompress(pk).unwrap().compress(), *pk);
+        }
+    }
+
+    #[test]
+    fn test_sha512_streaming() {
+        use sha512::{sha512, Sha512};
+
+        let mut input = [0u8; 300];
+        for (i, byte) in input.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+
+        // Every length, in one update and split into two updates
+        for len in 0..input.len() {
+            let expected = sha512(&input[..len]);
+
+            let mut hasher = Sha512::new();
+            hasher.update(&input[..len]);
+            assert_eq!(hasher.finalize(), expected);
+
+            for split in [0, 1, len / 2, len].iter().filter(|s| **s <= len) {
+                let mut hasher = Sha512::default();
+                hasher.update(&input[..*split]);
+                hasher.update(&input[*split..len]);
+                assert_eq!(hasher.finalize(), expected);
+            }
+        }
+
+        // One byte at a time, across several blocks
+        let mut hasher = Sha512::new();
+        for byte in input.iter() {
+            hasher.update(&[*byte]);
+        }
+        assert_eq!(hasher.clone().finalize(), sha512(&input));
+        hasher.update(b"");
+        assert_eq!(hasher.finalize(), sha512(&input));
+    }
+
+    #[cfg(feature = "digest")]
+    #[test]
+    fn test_sha512_digest() {
+        use digest::Digest;
+
+        fn hash<D: Digest>(chunks: &[&[u8]]) -> digest::Output<D> {
+            let mut hasher = D::new();
+            for chunk in chunks {
+                hasher.update(chunk);
+            }
+            hasher.finalize()
+        }
+
+        let mut input = [0u8; 300];
+        for (i, byte) in input.iter_mut().enumerate() {
+            *byte = (i * 7) as u8;
+        }
+
+        for len in [0, 1, 111, 112, 127, 128, 129, 255, 256, 300].iter() {
+            let (a, b) = input[..*len].split_at(len / 3);
+            assert_eq!(
+                hash::<sha512::Sha512>(&[a, b]).as_slice(),
+                hash::<sha2::Sha512>(&[a, b]).as_slice()
+            );
+        }
+
+        // finalize_reset leaves a fresh hasher behind
+        let mut hasher = <sha512::Sha512 as Digest>::new();
+        Digest::update(&mut hasher, b"abc");
+        let first = hasher.finalize_reset();
+        Digest::update(&mut hasher, b"abc");
+        assert_eq!(first, hasher.finalize_reset());
+        assert_eq!(first.as_slice(), &sha512::sha512(b"abc")[..]);
+        assert_eq!(
+            Digest::finalize(hasher).as_slice(),
+            sha2::Sha512::digest(b"").as_slice()
+        );
+
+        let mut hasher = <sha512::Sha512_256 as Digest>::new();
+        Digest::update(&mut hasher, b"abc");
+        assert_eq!(hasher.finalize_reset()[..], sha512::sha512_256(b"abc"));
+        assert_eq!(
+            hash::<sha512::Sha384>(&[b"ab", b"c"]).as_slice(),
+            hash::<sha2::Sha384>(&[b"abc"]).as_slice()
+        );
+        assert_eq!(
+            hash::<sha512::Sha512_224>(&[b"ab", b"c"]).as_slice(),
+            hash::<sha2::Sha512_224>(&[b"abc"]).as_slice()
+        );
+        assert_eq!(Digest::finalize(hasher)[..], sha512::sha512_256(b""));
+    }
+
+    fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+        let mut bytes = [0u8; N];
+        assert_eq!(hex.len(), 2 * N);
+        for (byte, pair) in bytes.iter_mut().zip(hex.as_bytes().chunks(2)) {
+            *byte = u8::from_str_radix(core::str::from_utf8(pair).unwrap(), 16).unwrap();
+        }
+        bytes
+    }
+
+    /// `s + l` in the scalar half of `sig`: the same scalar, but not reduced.
+    fn add_l(sig: [u8; 64]) -> [u8; 64] {
+        let mut s_plus_l = sig;
+        let mut carry = 0u16;
+        for i in 0..32 {
+            carry += s_plus_l[32 + i] as u16 + constants::BASEPOINT_ORDER[i] as u16;
+            s_plus_l[32 + i] = carry as u8;
+            carry >>= 8;
+        }
+        s_plus_l
+    }
+
+    #[test]
+    fn test_sha512_truncated_variants() {
+        use sha2::Digest;
+        use sha512::*;
+
+        // FIPS 180-4 examples: one-block and two-block messages
+        let one_block = b"abc";
+        let two_block = b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu";
+
+        assert_eq!(sha384(one_block), from_hex("cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed8086072ba1e7cc2358baeca134c825a7"));
+        assert_eq!(sha384(two_block), from_hex("09330c33f71147e83d192fc782cd1b4753111b173b3b05d22fa08086e3b0f712fcc7c71a557e2db966c3e9fa91746039"));
+        assert_eq!(
+            sha512_224(one_block),
+            from_hex("4634270f707b6a54daae7530460842e20e37ed265ceee9a43e8924aa")
+        );
+        assert_eq!(
+            sha512_224(two_block),
+            from_hex("23fec5bb94d60b23308192640b0c453335d664734fe40e7268674af9")
+        );
+        assert_eq!(
+            sha512_256(one_block),
+            from_hex("53048e2681941ef99b2e29b76b4c7dabe4c2d0c634fc6d46e0e2f13107e7af23")
+        );
+        assert_eq!(
+            sha512_256(two_block),
+            from_hex("3928e184fb8690f840da3988121d31be65cb9d3ef83ee6146feac861e19b563a")
+        );
+
+        let mut input = [0u8; 300];
+        for (i, byte) in input.iter_mut().enumerate() {
+            *byte = (i * 13) as u8;
+        }
+
+        for len in 0..input.len() {
+            let message = &input[..len];
+            let (a, b) = message.split_at(len / 2);
+
+            let mut hasher = Sha384::new();
+            hasher.update(a);
+            hasher.update(b);
+            assert_eq!(hasher.finalize(), sha384(message));
+            assert_eq!(sha384(message)[..], sha2::Sha384::digest(message)[..]);
+
+            let mut hasher = Sha512_224::default();
+            hasher.update(a);
+            hasher.update(b);
+            assert_eq!(hasher.finalize(), sha512_224(message));
+            assert_eq!(
+                sha512_224(message)[..],
+                sha2::Sha512_224::digest(message)[..]
+            );
+
+            let mut hasher = Sha512_256::new();
+            hasher.update(a);
+            hasher.update(b);
+            assert_eq!(hasher.finalize(), sha512_256(message));
+            assert_eq!(
+                sha512_256(message)[..],
+                sha2::Sha512_256::digest(message)[..]
+            );
+        }
+    }
+
+    #[test]
+    fn test_hmac_sha512_rfc4231() {
+        use hmac::{hmac_sha512, HmacSha512};
+
+        let large_key = [0xaa; 131];
+        let vectors: [(&[u8], &[u8], [u8; 64]); 6] = [
+            (&[0x0b; 20], b"Hi There", from_hex("87aa7cdea5ef619d4ff0b4241a1d6cb02379f4e2ce4ec2787ad0b30545e17cdedaa833b7d6b8a702038b274eaea3f4e4be9d914eeb61f1702e696c203a126854")),
+            (b"Jefe", b"what do ya want for nothing?", from_hex("164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea2505549758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737")),
+            (&[0xaa; 20], &[0xdd; 50], from_hex("fa73b0089d56a284efb0f0756c890be9b1b5dbdd8ee81a3655f83e33b2279d39bf3e848279a722c806b485a47e67c807b946a337bee8942674278859e13292fb")),
+            (&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25], &[0xcd; 50], from_hex("b0ba465637458c6990e5a8c5f61d4af7e576d97ff94b872de76f8050361ee3dba91ca5c11aa25eb4d679275cc5788063a5f19741120c4f2de2adebeb10a298dd")),
+            (&large_key, b"Test Using Larger Than Block-Size Key - Hash Key First", from_hex("80b24263c7c1a3ebb71493c1dd7be8b49b46d1f41b4aeec1121b013783f8f3526b56d037e05f2598bd0fd2215d6a1e5295e64f73f63f0aec8b915a985d786598")),
+            (&large_key, b"This is a test using a larger than block-size key and a larger than block-size data. The key needs to be hashed before being used by the HMAC algorithm.", from_hex("e37b6a775dc87dbaa4dfa9f96e5e3ffddebd71f8867289865df5a32d20cdc944b6022cac3c4982b10d5eeb55c3e4de15134676fb6de0446065c97440fa8c6a58")),
+        ];
+
+        for (key, data, tag) in vectors.iter() {
+            assert_eq!(hmac_sha512(key, data), *tag);
+
+            let mut mac = HmacSha512::new(key);
+            for chunk in data.chunks(7) {
+                mac.update(chunk);
+            }
+            assert_eq!(mac.finalize(), *tag);
+        }
+
+        // RFC 4231 test case 5 truncates the tag to 128 bits
+        assert_eq!(
+            hmac_sha512(&[0x0c; 20], b"Test With Truncation")[..16],
+            from_hex::<16>("415fad6271580a531d4179bc891d87a6")
+        );
+    }
+
+    #[test]
+    fn test_hkdf_sha512() {
+        use hkdf::*;
+
+        // The inputs of RFC 5869 test cases 1-3, with SHA-512 in place of SHA-256
+        let mut salt = [0u8; 80];
+        let mut ikm = [0u8; 80];
+        let mut info = [0u8; 80];
+        for i in 0..80 {
+            salt[i] = 0x60 + i as u8;
+            ikm[i] = i as u8;
+            info[i] = 0xb0 + i as u8;
+        }
+
+        let prk = hkdf_sha512_extract(&ikm[..13], &[0x0b; 22]);
+        assert_eq!(prk, from_hex("665799823737ded04a88e47e54a5890bb2c3d247c7a4254a8e61350723590a26c36238127d8661b88cf80ef802d57e2f7cebcf1e00e083848be19929c61b4237"));
+        let mut okm = [0u8; 42];
+        hkdf_sha512_expand(
+            &prk,
+            &[0xf0, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7, 0xf8, 0xf9],
+            &mut okm,
+        )
+        .unwrap();
+        assert_eq!(okm, from_hex("832390086cda71fb47625bb5ceb168e4c8e26a1a16ed34d9fc7fe92c1481579338da362cb8d9f925d7cb"));
+
+        let prk = hkdf_sha512_extract(&salt, &ikm);
+        assert_eq!(prk, from_hex("35672542907d4e142c00e84499e74e1de08be86535f924e022804ad775dde27ec86cd1e5b7d178c74489bdbeb30712beb82d4f97416c5a94ea81ebdf3e629e4a"));
+        let mut okm = [0u8; 82];
+        hkdf_sha512_expand(&prk, &info, &mut okm).unwrap();
+        assert_eq!(okm, from_hex("ce6c97192805b346e6161e821ed165673b84f400a2b514b2fe23d84cd189ddf1b695b48cbd1c8388441137b3ce28f16aa64ba33ba466b24df6cfcb021ecff235f6a2056ce3af1de44d572097a8505d9e7a93"));
+
+        let mut okm = [0u8; 42];
+        hkdf_sha512(b"", &[0x0b; 22], b"", &mut okm).unwrap();
+        assert_eq!(okm, from_hex("f5fa02b18298a72a8c23898a8703472c6eb179dc204c03425c970e3b164bf90fff22d04836d0e2343bac"));
+        assert_eq!(
+            hkdf_sha512_extract(b"", &[0x0b; 22]),
+            hkdf_sha512_extract(&[0; 64], &[0x0b; 22])
+        );
+
+        // Each output is a prefix of any longer one
+        let mut long = [0u8; MAX_OUTPUT_LEN];
+        hkdf_sha512_expand(&prk, b"info", &mut long).unwrap();
+        let mut short = [0u8; 100];
+        hkdf_sha512_expand(&prk, b"info", &mut short).unwrap();
+        assert_eq!(short[..], long[..100]);
+
+        let mut too_long = [0u8; MAX_OUTPUT_LEN + 1];
+        assert_eq!(
+            hkdf_sha512_expand(&prk, b"info", &mut too_long),
+            Err(Error::InvalidOutputLength)
+        );
+    }
+
+    #[test]
+    fn test_xeddsa_sign() {
+        use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+        let mut x25519_sk = [0u8; 32];
+        let mut random = [0u8; 64];
+        let mut msg = [0u8; 100];
+        for i in 0..32u8 {
+            for (j, byte) in x25519_sk.iter_mut().enumerate() {
+                *byte = (j as u8).wrapping_mul(31).wrapping_add(i.wrapping_mul(97));
+            }
+            random[i as usize] = i;
+            msg[i as usize] = i.wrapping_mul(3);
+
+            let ed25519_pk = curve25519_pk_to_ed25519(x25519_base(x25519_sk), 0);
+            let verifying_key = VerifyingKey::from_bytes(&ed25519_pk).unwrap();
+
+            let signature = xeddsa_sign(x25519_sk, &msg[..i as usize], random);
+            let signature = Signature::from_bytes(&signature);
+            assert!(verifying_key.verify(&msg[..i as usize], &signature).is_ok());
+            assert!(verifying_key
+                .verify_strict(&msg[..i as usize], &signature)
+                .is_ok());
+            assert!(verifying_key
+                .verify(&msg[..i as usize + 1], &signature)
+                .is_err());
+        }
+
+        // The nonce depends on the randomness, the key pair does not
+        let sig1 = xeddsa_sign(RFC8032_SK, b"abc", [1; 64]);
+        let sig2 = xeddsa_sign(RFC8032_SK, b"abc", [2; 64]);
+        assert_ne!(sig1[..32], sig2[..32]);
+        assert_eq!(sig1, xeddsa_sign(RFC8032_SK, b"abc", [1; 64]));
+    }
+
+    #[test]
+    fn test_xeddsa_verify() {
+        let x25519_sk = [0x42u8; 32];
+        let x25519_pk = x25519_base(x25519_sk);
+        let signature = xeddsa_sign(x25519_sk, b"message", [7; 64]);
+        assert_eq!(xeddsa_verify(x25519_pk, b"message", &signature), Ok(()));
+
+        // RFC 8032 TEST 1: the Ed25519 public key has sign bit 0, so its signatures are also
+        // XEdDSA signatures under the converted key
+        let rfc8032_x25519_pk = ed25519_pk_to_curve25519(RFC8032_PK);
+        assert_eq!(curve25519_pk_to_ed25519(rfc8032_x25519_pk, 0), RFC8032_PK);
+        assert_eq!(xeddsa_verify(rfc8032_x25519_pk, b"", &RFC8032_SIG), Ok(()));
+        assert_eq!(
+            xeddsa_verify(rfc8032_x25519_pk, b"x", &RFC8032_SIG),
+            Err(Error::InvalidSignature)
+        );
+
+        assert_eq!(
+            xeddsa_verify(x25519_pk, b"message", &signature[..63]),
+            Err(Error::InvalidSignatureLength)
+        );
+
+        let s_plus_l = add_l(signature);
+        assert_eq!(
+            xeddsa_verify(x25519_pk, b"message", &s_plus_l),
+            Err(Error::InvalidScalar)
+        );
+
+        // u = p and u with the top bit set are not canonical
+        let mut p = [0xff; 32];
+        p[0] = 0xed;
+        p[31] = 0x7f;
+        assert_eq!(
+            xeddsa_verify(p, b"message", &signature),
+            Err(Error::NonCanonicalEncoding)
+        );
+        let mut high_bit_u = x25519_pk;
+        high_bit_u[31] |= 0x80;
+        assert_eq!(
+            xeddsa_verify(high_bit_u, b"message", &signature),
+            Err(Error::NonCanonicalEncoding)
+        );
+
+        // R = (0, 1) encoded with y = 1 + p
+        let mut non_canonical_r = signature;
+        non_canonical_r[..32].copy_from_slice(&p);
+        non_canonical_r[0] = 0xee;
+        assert_eq!(
+            xeddsa_verify(x25519_pk, b"message", &non_canonical_r),
+            Err(Error::NonCanonicalEncoding)
+        );
+
+        let mut other_r = signature;
+        other_r[0] ^= 1;
+        assert!(xeddsa_verify(x25519_pk, b"message", &other_r).is_err());
+    }
+
+    #[test]
+    fn test_xeddsa_vectors() {
+        // Generated with an independent big-integer implementation of the XEdDSA specification,
+        // which does not publish test vectors. The message is the bytes 0, 1, ..., len - 1.
+        // (k, u, message length, Z, XEdDSA signature, VXEdDSA signature, VRF output)
+        let vectors = [
+            (
+                "01080f161d242b323940474e555c636a71787f868d949ba2a9b0b7bec5ccd3da",
+                "c8feca81be196cdf2cadeabf13c4903d7632dce4955aa68b6e5d9adef54e2616",
+                0,
+                "000306090c0f1215181b1e2124272a2d303336393c3f4245484b4e5154575a5d606366696c6f7275787b7e8184878a8d909396999c9fa2a5a8abaeb1b4b7babd",
+                "3f861c31c699534c446744e6b1c589b68500cfbe9717cd817bb617b1cc758916a7098ada3cd6b279140b780c6d899addaacb0c73580d0f1b91fb3f5f9783bd0e",
+                "81a2a4f52fbc22d3ad71033539d2a60f6c1c6becd6c4e158be2e985e30ed9ceee76c26b88c1e81d640b9c7986d8b09d78c8304f7b93bf123159139ddd9b4d3014089ba8b0cbd887112780e2f7317d1a3a98163aa9744c43192ab3194c98fce0d",
+                "2764c624b6884b1051c168579c1cb72e278ff332508b63cbf5cb249faa537f1f",
+            ),
+            (
+                "0e151c232a31383f464d545b626970777e858c939aa1a8afb6bdc4cbd2d9e0e7",
+                "7d0c85cb9d79059839640ae51624055acf5f1b1c9480bba37c1d66d809009217",
+                5,
+                "0306090c0f1215181b1e2124272a2d303336393c3f4245484b4e5154575a5d606366696c6f7275787b7e8184878a8d909396999c9fa2a5a8abaeb1b4b7babdc0",
+                "06ee3b64ca78bfeab13e3da4d4f06be594a4848979e27aeeaa1a2fd6cb04964c22b108a67ddbf6f07cc91b3e8e644e61d3b6600efcb9b29edb21502995333e0e",
+                "955b540d65aaf10d1324893377686904d206632971c819358a4959d2eab0eab4cf1767f5151817cf8896ecdf38821a2ea89d4ed55cf49bb1b8f71fadaa285c0966484e049cf348b4b36cb50810954f19b13c3626964418d333e3531e57f5de05",
+                "be078845811a0cef83cd567a99bbec30c5d24f31dd63ef3cf6be8efb6d903653",
+            ),
+            (
+                "1b222930373e454c535a61686f767d848b9299a0a7aeb5bcc3cad1d8dfe6edf4",
+                "0b753192af2cd9b0b2e1d0456a08d3152d2f30d74100b23810a55165445bfa2f",
+                10,
+                "06090c0f1215181b1e2124272a2d303336393c3f4245484b4e5154575a5d606366696c6f7275787b7e8184878a8d909396999c9fa2a5a8abaeb1b4b7babdc0c3",
+                "2f901cd335ac37de27b06fd310627269dfea948dc8f131fb1cf6d897ecbd846ffd5610421c67704f363a32748a0b3931aeab9e564dfbf2551764f0f2fc258302",
+                "a621eff710245994e2e189b06a4818e4773363bba4492f6c1d4e08e888a2647c75d322692ce3fc6012d4af199b4b710891b8b69a11b03bd4b84300b67f3108046347afbaae19525c3f21acb23e77c5401fc8c7a77f4ea01099b1f9979b308b0f",
+                "d8dd0f10bde4e714c556a4923ce1167af66a05f457928b79a3c8b4d7f530f503",
+            ),
+        ];
+        let message: [u8; 16] = core::array::from_fn(|i| i as u8);
+
+        for (k, u, len, random, xsig, vsig, output) in vectors.iter() {
+            let k = from_hex::<32>(k);
+            let u = from_hex::<32>(u);
+            let msg = &message[..*len];
+            let random = from_hex::<64>(random);
+            let vsig = from_hex::<96>(vsig);
+            let output = from_hex::<32>(output);
+
+            assert_eq!(x25519_base(k), u);
+            assert_eq!(xeddsa_sign(k, msg, random), from_hex::<64>(xsig));
+            assert_eq!(vxeddsa_sign(k, msg, random), (vsig, output));
+            assert_eq!(vxeddsa_verify(u, msg, &vsig), Ok(output));
+        }
+    }
+
+    #[test]
+    fn test_vxeddsa() {
+        let x25519_sk = [0x42u8; 32];
+        let x25519_pk = x25519_base(x25519_sk);
+        let (signature, output) = vxeddsa_sign(x25519_sk, b"message", [7; 64]);
+        assert_eq!(
+            vxeddsa_verify(x25519_pk, b"message", &signature),
+            Ok(output)
+        );
+
+        // Same VRF output for any randomness, a different one for another message
+        let (other_signature, other_output) = vxeddsa_sign(x25519_sk, b"message", [8; 64]);
+        assert_ne!(signature, other_signature);
+        assert_eq!(output, other_output);
+        assert_ne!(vxeddsa_sign(x25519_sk, b"message!", [7; 64]).1, output);
+
+        assert_eq!(
+            vxeddsa_verify(x25519_pk, b"message!", &signature),
+            Err(Error::InvalidSignature)
+        );
+        assert_eq!(
+            vxeddsa_verify(x25519_base([0x43; 32]), b"message", &signature),
+            Err(Error::InvalidSignature)
+        );
+        assert_eq!(
+            vxeddsa_verify(x25519_pk, b"message", &signature[..64]),
+            Err(Error::InvalidSignatureLength)
+        );
+
+        // Tampering with V, h or s
+        for i in [0, 32, 64].iter() {
+            let mut tampered = signature;
+            tampered[*i] ^= 1;
+            assert!(vxeddsa_verify(x25519_pk, b"message", &tampered).is_err());
+        }
+
+        let mut h_too_large = signature;
+        h_too_large[63] = 0xff;
+        assert_eq!(
+            vxeddsa_verify(x25519_pk, b"message", &h_too_large),
+            Err(Error::InvalidScalar)
+        );
+
+        // V of small order
+        let mut small_order_v = signature;
+        small_order_v[..32].copy_from_slice(&EdwardsPoint::identity().compress());
+        assert_eq!(
+            vxeddsa_verify(x25519_pk, b"message", &small_order_v),
+            Err(Error::SmallOrder)
+        );
+    }
+
+    #[test]
+    fn test_scalar() {
+        let l = constants::BASEPOINT_ORDER;
+        assert_eq!(Scalar::from_bytes_mod_order(l), Scalar::zero());
+        assert_eq!(Scalar::from_canonical_bytes(l), Err(Error::InvalidScalar));
+
+        let mut l_minus_one = l;
+        l_minus_one[0] -= 1;
+        let minus_one = Scalar::from_canonical_bytes(l_minus_one).unwrap();
+        assert_eq!(minus_one, -&Scalar::one());
+        assert_eq!(&minus_one + &Scalar::one(), Scalar::zero());
+        assert_eq!(&Scalar::zero() - &Scalar::one(), minus_one);
+        assert_eq!(&minus_one * &minus_one, Scalar::one());
+
+        // 2^256 - 1 and 2^512 - 1
+        assert!(Scalar::from_canonical_bytes([0xff; 32]).is_err());
+        assert_eq!(
+            Scalar::from_bytes_mod_order([0xff; 32]).to_bytes(),
+            from_hex("1c95988d7431ecd670cf7d73f45befc6feffffffffffffffffffffffffffff0f")
+        );
+        assert_eq!(
+            Scalar::from_bytes_mod_order_wide(&[0xff; 64]).to_bytes(),
+            from_hex("000f9c44e31106a447938568a71b0ed065bef517d273ecce3d9a307c1b419903")
+        );
+
+        let x = Scalar::from_bytes_mod_order(core::array::from_fn(|i| i as u8 + 1));
+        assert_eq!(
+            x.to_bytes(),
+            from_hex("275a174ad03fe2575cd01bc64f1a51e61012131415161718191a1b1c1d1e1f00")
+        );
+        assert_eq!(
+            (&x * &x).to_bytes(),
+            from_hex("acee139cd798c73023be9724a111f931034ef7068bd3e387291a296fc808c001")
+        );
+        assert_eq!(
+            x.invert().to_bytes(),
+            from_hex("f5e24163b7c5c1d85cde846227683f22618f1a1c2f0cbfbe87b1bfa6ea7bcf09")
+        );
+        assert_eq!(&x * &x.invert(), Scalar::one());
+        assert_eq!(
+            (&Scalar::one() + &Scalar::one()).invert().to_bytes(),
+            from_hex("f7e97a2e8d31092c6bce7b51ef7c6f0a00000000000000000000000000000008")
+        );
+        assert_eq!(Scalar::zero().invert(), Scalar::zero());
+
+        // RFC 8032 TEST 1: S = (r + k * a) mod l
+        let expanded_sk = sha512::sha512(&RFC8032_SK);
+        let a = Scalar::from_bytes_mod_order(ed25519_sk_to_curve25519(RFC8032_SK));
+
+        let mut hasher = sha512::Sha512::new();
+        hasher.update(&expanded_sk[32..]);
+        let r = Scalar::from_bytes_mod_order_wide(&hasher.finalize());
+        let big_r = EdwardsPoint::mul_base(r.as_bytes()).compress();
+
+        let mut hasher = sha512::Sha512::new();
+        hasher.update(&big_r);
+        hasher.update(&RFC8032_PK);
+        let k = Scalar::from_bytes_mod_order_wide(&hasher.finalize());
+
+        assert_eq!(big_r[..], RFC8032_SIG[..32]);
+        assert_eq!(
+            Scalar::mul_add(&k, &a, &r).as_bytes()[..],
+            RFC8032_SIG[32..]
+        );
+        assert_eq!(&(&k * &a) + &r, Scalar::mul_add(&k, &a, &r));
+    }
+
+    #[test]
+    fn test_ed25519_expanded_sk_to_curve25519() {
+        let mut expanded_sk = sha512::sha512(&RFC8032_SK);
+        expanded_sk[..32].copy_from_slice(&ed25519_sk_to_curve25519(RFC8032_SK));
+
+        let curve25519_sk = ed25519_expanded_sk_to_curve25519(expanded_sk);
+        assert_eq!(curve25519_sk, ed25519_sk_to_curve25519(RFC8032_SK));
+        assert_eq!(
+            try_ed25519_expanded_sk_to_curve25519(expanded_sk),
+            Ok(curve25519_sk)
+        );
+        assert_eq!(
+            x25519_base(curve25519_sk),
+            ed25519_pk_to_curve25519(RFC8032_PK)
+        );
+
+        // An unclamped scalar is returned as is, but rejected by the checked variant
+        expanded_sk[31] &= 0x3f;
+        assert_eq!(
+            ed25519_expanded_sk_to_curve25519(expanded_sk)[31],
+            expanded_sk[31]
+        );
+        assert_eq!(
+            try_ed25519_expanded_sk_to_curve25519(expanded_sk),
+            Err(Error::UnclampedScalar)
+        );
+    }
+
     #[test]
     fn test_ed25519_sign_to_curve25519() {
         assert_eq!(
             ed25519_sign_to_curve25519(ED25519_PK, ED25519_SIGN),
-            CURVE25519_SIGN
+            Ok(CURVE25519_SIGN)
+        );
+
+        // A signature that already carries a sign bit
+        let mut sign = ED25519_SIGN;
+        sign[63] |= 0x80;
+        assert_eq!(
+            ed25519_sign_to_curve25519(ED25519_PK, sign),
+            Err(Error::InvalidScalar)
         );
     }
+
+    #[test]
+    fn test_try_ed25519_sign_to_curve25519() {
+        assert_eq!(
+            try_ed25519_sign_to_curve25519(ED25519_PK, &ED25519_SIGN),
+            Ok(CURVE25519_SIGN)
+        );
+        assert_eq!(
+            try_ed25519_sign_to_curve25519(ED25519_PK, &ED25519_SIGN[..63]),
+            Err(Error::InvalidSignatureLength)
+        );
+
+        let mut pk = [0u8; 32];
+        pk[0] = 2;
+        assert_eq!(
+            try_ed25519_sign_to_curve25519(pk, &ED25519_SIGN),
+            Err(Error::NotOnCurve)
+        );
+
+        // s = l is not reduced
+        let mut sign = ED25519_SIGN;
+        sign[32..].copy_from_slice(&constants::BASEPOINT_ORDER);
+        assert_eq!(
+            try_ed25519_sign_to_curve25519(ED25519_PK, &sign),
+            Err(Error::InvalidScalar)
+        );
+        sign[32] -= 1;
+        assert!(try_ed25519_sign_to_curve25519(ED25519_PK, &sign).is_ok());
+
+        // A signature that already carries a sign bit
+        let mut sign = ED25519_SIGN;
+        sign[63] |= 0x80;
+        assert_eq!(
+            try_ed25519_sign_to_curve25519(ED25519_PK, &sign),
+            Err(Error::InvalidScalar)
+        );
+    }
+
+    #[test]
+    fn test_curve25519_sign_to_ed25519() {
+        assert_eq!(
+            curve25519_sign_to_ed25519(CURVE25519_SIGN),
+            (ED25519_SIGN, 0)
+        );
+
+        // Round trip through an XEdDSA-style signature with a negative public key
+        let mut pk = RFC8032_PK;
+        pk[31] |= 0x80;
+        let curve25519_sign = ed25519_sign_to_curve25519(pk, ED25519_SIGN).unwrap();
+        assert_eq!(curve25519_sign[63] >> 7, 1);
+        assert_eq!(
+            curve25519_sign_to_ed25519(curve25519_sign),
+            (ED25519_SIGN, 1)
+        );
+
+        let (_, sign_bit) = curve25519_sign_to_ed25519(curve25519_sign);
+        assert_eq!(
+            curve25519_pk_to_ed25519(ed25519_pk_to_curve25519(pk), sign_bit),
+            pk
+        );
+    }
+
+    #[test]
+    fn test_ed25519_sign_verify() {
+        use ed25519_dalek::{Signer, SigningKey};
+
+        assert_eq!(ed25519_sign(RFC8032_SK, b""), RFC8032_SIG);
+        assert_eq!(ed25519_verify(RFC8032_PK, b"", &RFC8032_SIG), Ok(()));
+        assert_eq!(ed25519_verify_strict(RFC8032_PK, b"", &RFC8032_SIG), Ok(()));
+
+        for (i, msg) in [&b""[..], b"abc", &[0x5a; 300]].iter().enumerate() {
+            let sk = [i as u8 + 1; 32];
+            let expected = SigningKey::from_bytes(&sk).sign(msg).to_bytes();
+            assert_eq!(ed25519_sign(sk, msg), expected);
+        }
+
+        let mut tampered = RFC8032_SIG;
+        tampered[40] ^= 1;
+        assert_eq!(
+            ed25519_verify(RFC8032_PK, b"", &tampered),
+            Err(Error::InvalidSignature)
+        );
+        assert_eq!(
+            ed25519_verify(RFC8032_PK, b"", &RFC8032_SIG[..63]),
+            Err(Error::InvalidSignatureLength)
+        );
+
+        let s_plus_l = add_l(RFC8032_SIG);
+        assert_eq!(
+            ed25519_verify(RFC8032_PK, b"", &s_plus_l),
+            Err(Error::InvalidScalar)
+        );
+
+        // With the identity as public key and R, s = 0 is valid for every message
+        let mut identity = [0u8; 32];
+        identity[0] = 1;
+        let mut forged = [0u8; 64];
+        forged[..32].copy_from_slice(&identity);
+        assert_eq!(ed25519_verify(identity, b"any message", &forged), Ok(()));
+        assert_eq!(
+            ed25519_verify_strict(identity, b"any message", &forged),
+            Err(Error::SmallOrder)
+        );
+
+        // A small-order R with a valid key is rejected in strict mode too
+        let mut small_r = RFC8032_SIG;
+        small_r[..32].copy_from_slice(&identity);
+        assert_eq!(
+            ed25519_verify_strict(RFC8032_PK, b"", &small_r),
+            Err(Error::SmallOrder)
+        );
+    }
+
+    #[test]
+    fn test_x25519_rfc7748() {
+        // RFC 7748, section 5.2
+        let vectors = [
+            (
+                [
+                    0xa5, 0x46, 0xe3, 0x6b, 0xf0, 0x52, 0x7c, 0x9d, 0x3b, 0x16, 0x15, 0x4b, 0x82,
+                    0x46, 0x5e, 0xdd, 0x62, 0x14, 0x4c, 0x0a, 0xc1, 0xfc, 0x5a, 0x18, 0x50, 0x6a,
+                    0x22, 0x44, 0xba, 0x44, 0x9a, 0xc4,
+                ],
+                [
+                    0xe6, 0xdb, 0x68, 0x67, 0x58, 0x30, 0x30, 0xdb, 0x35, 0x94, 0xc1, 0xa4, 0x24,
+                    0xb1, 0x5f, 0x7c, 0x72, 0x66, 0x24, 0xec, 0x26, 0xb3, 0x35, 0x3b, 0x10, 0xa9,
+                    0x03, 0xa6, 0xd0, 0xab, 0x1c, 0x4c,
+                ],
+                [
+                    0xc3, 0xda, 0x55, 0x37, 0x9d, 0xe9, 0xc6, 0x90, 0x8e, 0x94, 0xea, 0x4d, 0xf2,
+                    0x8d, 0x08, 0x4f, 0x32, 0xec, 0xcf, 0x03, 0x49, 0x1c, 0x71, 0xf7, 0x54, 0xb4,
+                    0x07, 0x55, 0x77, 0xa2, 0x85, 0x52,
+                ],
+            ),
+            (
+                [
+                    0x4b, 0x66, 0xe9, 0xd4, 0xd1, 0xb4, 0x67, 0x3c, 0x5a, 0xd2, 0x26, 0x91, 0x95,
+                    0x7d, 0x6a, 0xf5, 0xc1, 0x1b, 0x64, 0x21, 0xe0, 0xea, 0x01, 0xd4, 0x2c, 0xa4,
+                    0x16, 0x9e, 0x79, 0x18, 0xba, 0x0d,
+                ],
+                [
+                    0xe5, 0x21, 0x0f, 0x12, 0x78, 0x68, 0x11, 0xd3, 0xf4, 0xb7, 0x95, 0x9d, 0x05,
+                    0x38, 0xae, 0x2c, 0x31, 0xdb, 0xe7, 0x10, 0x6f, 0xc0, 0x3c, 0x3e, 0xfc, 0x4c,
+                    0xd5, 0x49, 0xc7, 0x15, 0xa4, 0x93,
+                ],
+                [
+                    0x95, 0xcb, 0xde, 0x94, 0x76, 0xe8, 0x90, 0x7d, 0x7a, 0xad, 0xe4, 0x5c, 0xb4,
+                    0xb8, 0x73, 0xf8, 0x8b, 0x59, 0x5a, 0x68, 0x79, 0x9f, 0xa1, 0x52, 0xe6, 0xf8,
+                    0xf7, 0x64, 0x7a, 0xac, 0x79, 0x57,
+                ],
+            ),
+        ];
+        for (scalar, u, expected) in vectors.iter() {
+            assert_eq!(x25519(*scalar, *u), *expected);
+        }
+    }
+
+    #[test]
+    fn test_x25519_rfc7748_iterated() {
+        let mut k = [0u8; 32];
+        k[0] = 9;
+        let mut u = k;
+
+        for i in 0..1000 {
+            let result = x25519(k, u);
+            u = k;
+            k = result;
+
+            if i == 0 {
+                assert_eq!(
+                    k,
+                    [
+                        0x42, 0x2c, 0x8e, 0x7a, 0x62, 0x27, 0xd7, 0xbc, 0xa1, 0x35, 0x0b, 0x3e,
+                        0x2b, 0xb7, 0x27, 0x9f, 0x78, 0x97, 0xb8, 0x7b, 0xb6, 0x85, 0x4b, 0x78,
+                        0x3c, 0x60, 0xe8, 0x03, 0x11, 0xae, 0x30, 0x79
+                    ]
+                );
+            }
+        }
+
+        assert_eq!(
+            k,
+            [
+                0x68, 0x4c, 0xf5, 0x9b, 0xa8, 0x33, 0x09, 0x55, 0x28, 0x00, 0xef, 0x56, 0x6f, 0x2f,
+                0x4d, 0x3c, 0x1c, 0x38, 0x87, 0xc4, 0x93, 0x60, 0xe3, 0x87, 0x5f, 0x2e, 0xb9, 0x4d,
+                0x99, 0x53, 0x2c, 0x51
+            ]
+        );
+    }
+
+    #[test]
+    fn test_x25519_rfc7748_diffie_hellman() {
+        // RFC 7748, section 6.1
+        let alice_sk = [
+            0x77, 0x07, 0x6d, 0x0a, 0x73, 0x18, 0xa5, 0x7d, 0x3c, 0x16, 0xc1, 0x72, 0x51, 0xb2,
+            0x66, 0x45, 0xdf, 0x4c, 0x2f, 0x87, 0xeb, 0xc0, 0x99, 0x2a, 0xb1, 0x77, 0xfb, 0xa5,
+            0x1d, 0xb9, 0x2c, 0x2a,
+        ];
+        let alice_pk = [
+            0x85, 0x20, 0xf0, 0x09, 0x89, 0x30, 0xa7, 0x54, 0x74, 0x8b, 0x7d, 0xdc, 0xb4, 0x3e,
+            0xf7, 0x5a, 0x0d, 0xbf, 0x3a, 0x0d, 0x26, 0x38, 0x1a, 0xf4, 0xeb, 0xa4, 0xa9, 0x8e,
+            0xaa, 0x9b, 0x4e, 0x6a,
+        ];
+        let bob_sk = [
+            0x5d, 0xab, 0x08, 0x7e, 0x62, 0x4a, 0x8a, 0x4b, 0x79, 0xe1, 0x7f, 0x8b, 0x83, 0x80,
+            0x0e, 0xe6, 0x6f, 0x3b, 0xb1, 0x29, 0x26, 0x18, 0xb6, 0xfd, 0x1c, 0x2f, 0x8b, 0x27,
+            0xff, 0x88, 0xe0, 0xeb,
+        ];
+        let bob_pk = [
+            0xde, 0x9e, 0xdb, 0x7d, 0x7b, 0x7d, 0xc1, 0xb4, 0xd3, 0x5b, 0x61, 0xc2, 0xec, 0xe4,
+            0x35, 0x37, 0x3f, 0x83, 0x43, 0xc8, 0x5b, 0x78, 0x67, 0x4d, 0xad, 0xfc, 0x7e, 0x14,
+            0x6f, 0x88, 0x2b, 0x4f,
+        ];
+        let shared = [
+            0x4a, 0x5d, 0x9d, 0x5b, 0xa4, 0xce, 0x2d, 0xe1, 0x72, 0x8e, 0x3b, 0xf4, 0x80, 0x35,
+            0x0f, 0x25, 0xe0, 0x7e, 0x21, 0xc9, 0x47, 0xd1, 0x9e, 0x33, 0x76, 0xf0, 0x9b, 0x3c,
+            0x1e, 0x16, 0x17, 0x42,
+        ];
+
+        assert_eq!(x25519_base(alice_sk), alice_pk);
+        assert_eq!(x25519_base(bob_sk), bob_pk);
+        assert_eq!(x25519(alice_sk, bob_pk), shared);
+        assert_eq!(x25519(bob_sk, alice_pk), shared);
+    }
+
+    #[test]
+    fn test_keypair_from_ed25519() {
+        let keypair = Keypair::from_ed25519(RFC8032_SK, RFC8032_PK).unwrap();
+        assert_eq!(*keypair.secret(), ed25519_sk_to_curve25519(RFC8032_SK));
+        assert_eq!(*keypair.public(), ed25519_pk_to_curve25519(RFC8032_PK));
+
+        assert!(matches!(
+            Keypair::from_ed25519(ED25519_SK, RFC8032_PK),
+            Err(Error::KeyMismatch)
+        ));
+
+        let mut identity = [0u8; 32];
+        identity[0] = 1;
+        assert!(matches!(
+            Keypair::from_ed25519(RFC8032_SK, identity),
+            Err(Error::Identity)
+        ));
+    }
+
+    #[test]
+    fn test_keypair_from_ed25519_secret_key() {
+        let mut sk = [0u8; 64];
+        sk[..32].copy_from_slice(&RFC8032_SK);
+        sk[32..].copy_from_slice(&RFC8032_PK);
+
+        let keypair = Keypair::from_ed25519_secret_key(sk).unwrap();
+        assert_eq!(*keypair.secret(), ed25519_sk_to_curve25519(RFC8032_SK));
+        assert_eq!(*keypair.public(), ed25519_pk_to_curve25519(RFC8032_PK));
+
+        sk[32..].copy_from_slice(&ED25519_PK);
+        assert!(matches!(
+            Keypair::from_ed25519_secret_key(sk),
+            Err(Error::KeyMismatch)
+        ));
+
+        // The negated public key has the same Curve25519 public key
+        sk[32..].copy_from_slice(&RFC8032_PK);
+        sk[63] ^= 0x80;
+        assert!(matches!(
+            Keypair::from_ed25519_secret_key(sk),
+            Err(Error::KeyMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_x25519_converted_keys() {
+        let curve25519_sk = ed25519_sk_to_curve25519(RFC8032_SK);
+        let curve25519_pk = ed25519_pk_to_curve25519(RFC8032_PK);
+        assert_eq!(x25519_base(curve25519_sk), curve25519_pk);
+    }
 }
@@ -0,0 +1,64 @@
+#![allow(clippy::all)]
+#![allow(non_snake_case)]
+use crate::ct::{Choice, ConditionallySelectable};
+use crate::field::FieldElement;
+
+/// The constant (A - 2)/4 = 121665 used in the ladder step.
+const A24: [u8; 32] = [
+    65, 219, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0,
+];
+
+/// Clamp a scalar as in RFC 7748, section 5 (`decodeScalar25519`).
+pub fn clamp_scalar(mut k: [u8; 32]) -> [u8; 32] {
+    k[0] &= 248;
+    k[31] &= 127;
+    k[31] |= 64;
+
+    k
+}
+
+/// Compute the u-coordinate of [k]P, given the u-coordinate of P.
+///
+/// This is the Montgomery ladder from RFC 7748, section 5. The scalar
+/// is used as given, without clamping. Every iteration performs the
+/// same operations, and the conditional swaps are done with masks, so
+/// the running time does not depend on the bits of the scalar.
+pub fn mul(k: &[u8; 32], u: &FieldElement) -> FieldElement {
+    let a24 = FieldElement::from_bytes(&A24);
+
+    let x1 = *u;
+    let mut x2 = FieldElement::one();
+    let mut z2 = FieldElement::zero();
+    let mut x3 = *u;
+    let mut z3 = FieldElement::one();
+    let mut swap = 0u8;
+
+    for t in (0..255).rev() {
+        let k_t = (k[t >> 3] >> (t & 7)) & 1;
+        swap ^= k_t;
+        FieldElement::conditional_swap(&mut x2, &mut x3, Choice::from(swap));
+        FieldElement::conditional_swap(&mut z2, &mut z3, Choice::from(swap));
+        swap = k_t;
+
+        let A = &x2 + &z2;
+        let AA = A.square();
+        let B = &x2 - &z2;
+        let BB = B.square();
+        let E = &AA - &BB;
+        let C = &x3 + &z3;
+        let D = &x3 - &z3;
+        let DA = &D * &A;
+        let CB = &C * &B;
+
+        x3 = (&DA + &CB).square();
+        z3 = &x1 * &(&DA - &CB).square();
+        x2 = &AA * &BB;
+        z2 = &E * &(&AA + &(&a24 * &E));
+    }
+
+    FieldElement::conditional_swap(&mut x2, &mut x3, Choice::from(swap));
+    FieldElement::conditional_swap(&mut z2, &mut z3, Choice::from(swap));
+
+    &x2 * &z2.invert()
+}
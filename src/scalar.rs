@@ -0,0 +1,418 @@
+// Adapted from the u64 scalar backend (src/backend/serial/u64/scalar.rs) and src/scalar.rs of
+// curve25519-dalek, <https://github.com/dalek-cryptography/curve25519-dalek>,
+// which is distributed under the following licence (BSD-3-Clause):
+//
+// Copyright (c) 2016-2021 isis agora lovecruft. All rights reserved.
+// Copyright (c) 2016-2021 Henry de Valence. All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are
+// met:
+//
+// 1. Redistributions of source code must retain the above copyright
+// notice, this list of conditions and the following disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright
+// notice, this list of conditions and the following disclaimer in the
+// documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its
+// contributors may be used to endorse or promote products derived from
+// this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS
+// IS" AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED
+// TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A
+// PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT
+// HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED
+// TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR
+// PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF
+// LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING
+// NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE OF THIS
+// SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+#![allow(clippy::all)]
+use crate::constants;
+use crate::ct::{Choice, ConditionallySelectable, ConstantTimeEq};
+use crate::error::Error;
+use core::ops::{Add, Mul, Neg, Sub};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// An integer modulo the group order of the Ed25519 base point,
+/// `l = 2^252 + 27742317777372353535851937790883648493`.
+///
+/// A `Scalar` is always reduced. Arithmetic runs in constant time.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::Scalar;
+/// let a = Scalar::from_bytes_mod_order([7u8; 32]);
+/// let b = Scalar::from_bytes_mod_order_wide(&[9u8; 64]);
+/// let c = Scalar::one();
+/// assert_eq!(Scalar::mul_add(&a, &b, &c), &(&a * &b) + &c);
+/// assert_eq!(&a * &a.invert(), Scalar::one());
+/// ```
+///
+#[derive(Copy, Clone, Debug)]
+pub struct Scalar {
+    bytes: [u8; 32],
+}
+
+impl ConstantTimeEq for Scalar {
+    fn ct_eq(&self, other: &Scalar) -> Choice {
+        self.bytes[..].ct_eq(&other.bytes[..])
+    }
+}
+
+impl PartialEq for Scalar {
+    fn eq(&self, other: &Scalar) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for Scalar {}
+
+impl ConditionallySelectable for Scalar {
+    fn conditional_select(a: &Scalar, b: &Scalar, choice: Choice) -> Scalar {
+        let a = Scalar52::from_bytes(&a.bytes);
+        let b = Scalar52::from_bytes(&b.bytes);
+
+        let mut limbs = [0u64; 5];
+        for i in 0..5 {
+            limbs[i] = u64::conditional_select(&a.0[i], &b.0[i], choice);
+        }
+        Scalar52(limbs).into_scalar()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Zeroize for Scalar {
+    fn zeroize(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+impl<'a, 'b> Add<&'b Scalar> for &'a Scalar {
+    type Output = Scalar;
+    fn add(self, rhs: &'b Scalar) -> Scalar {
+        Scalar52::add(&self.unpack(), &rhs.unpack()).into_scalar()
+    }
+}
+
+impl<'a, 'b> Sub<&'b Scalar> for &'a Scalar {
+    type Output = Scalar;
+    fn sub(self, rhs: &'b Scalar) -> Scalar {
+        Scalar52::sub(&self.unpack(), &rhs.unpack()).into_scalar()
+    }
+}
+
+impl<'a, 'b> Mul<&'b Scalar> for &'a Scalar {
+    type Output = Scalar;
+    fn mul(self, rhs: &'b Scalar) -> Scalar {
+        Scalar52::mul(&self.unpack(), &rhs.unpack()).into_scalar()
+    }
+}
+
+impl<'a> Neg for &'a Scalar {
+    type Output = Scalar;
+    fn neg(self) -> Scalar {
+        Scalar52::sub(&Scalar52::zero(), &self.unpack()).into_scalar()
+    }
+}
+
+impl Scalar {
+    /// Construct zero.
+    pub fn zero() -> Scalar {
+        Scalar { bytes: [0; 32] }
+    }
+
+    /// Construct one.
+    pub fn one() -> Scalar {
+        let mut bytes = [0; 32];
+        bytes[0] = 1;
+        Scalar { bytes }
+    }
+
+    /// Reduce a 256-bit little-endian integer modulo `l`.
+    pub fn from_bytes_mod_order(bytes: [u8; 32]) -> Scalar {
+        let mut wide = [0u8; 64];
+        wide[..32].copy_from_slice(&bytes);
+        Scalar::from_bytes_mod_order_wide(&wide)
+    }
+
+    /// Reduce a 512-bit little-endian integer, such as a SHA-512 output,
+    /// modulo `l`.
+    pub fn from_bytes_mod_order_wide(bytes: &[u8; 64]) -> Scalar {
+        Scalar52::from_bytes_wide(bytes).into_scalar()
+    }
+
+    /// Decode a little-endian integer that must already be reduced modulo
+    /// `l`, as the `s` half of a signature is.
+    ///
+    /// Returns `Error::InvalidScalar` if it is not. The check runs in
+    /// constant time.
+    pub fn from_canonical_bytes(bytes: [u8; 32]) -> Result<Scalar, Error> {
+        let reduced = Scalar::from_bytes_mod_order(bytes);
+        if bool::from(reduced.bytes[..].ct_eq(&bytes[..])) {
+            Ok(reduced)
+        } else {
+            Err(Error::InvalidScalar)
+        }
+    }
+
+    /// Encode as 32 little-endian bytes.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.bytes
+    }
+
+    /// View the little-endian encoding.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.bytes
+    }
+
+    /// Compute `a * b + c`.
+    pub fn mul_add(a: &Scalar, b: &Scalar, c: &Scalar) -> Scalar {
+        let ab = Scalar52::mul(&a.unpack(), &b.unpack());
+        Scalar52::add(&ab, &c.unpack()).into_scalar()
+    }
+
+    /// Compute the multiplicative inverse, `self^(l - 2)`.
+    ///
+    /// The inverse of zero is zero.
+    pub fn invert(&self) -> Scalar {
+        let mut exponent = constants::BASEPOINT_ORDER;
+        exponent[0] -= 2;
+
+        // Square-and-multiply in Montgomery form. The exponent is public,
+        // so branching on its bits does not leak anything.
+        let base = Scalar52::montgomery_mul(&self.unpack(), &RR);
+        let mut result = R;
+        for i in (0..253).rev() {
+            result = Scalar52::montgomery_mul(&result, &result);
+            if (exponent[i >> 3] >> (i & 7)) & 1 == 1 {
+                result = Scalar52::montgomery_mul(&result, &base);
+            }
+        }
+
+        // Leave Montgomery form: xR * 1 / R = x
+        let mut one = Scalar52::zero();
+        one.0[0] = 1;
+        Scalar52::montgomery_mul(&result, &one).into_scalar()
+    }
+
+    fn unpack(&self) -> Scalar52 {
+        Scalar52::from_bytes(&self.bytes)
+    }
+}
+
+/// A scalar as five 52-bit limbs, used for arithmetic.
+///
+/// Multiplication uses Montgomery reduction with R = 2^260. There are no
+/// secret-dependent branches; reductions subtract `l` and add it back
+/// with a mask.
+#[derive(Copy, Clone)]
+struct Scalar52([u64; 5]);
+
+const MASK: u64 = (1u64 << 52) - 1;
+
+/// `l` in 52-bit limbs.
+const L: Scalar52 = Scalar52([
+    0x0002631a5cf5d3ed,
+    0x000dea2f79cd6581,
+    0x000000000014def9,
+    0x0000000000000000,
+    0x0000100000000000,
+]);
+
+/// `-l^-1 mod 2^52`.
+const LFACTOR: u64 = 0x51da312547e1b;
+
+/// `R = 2^260 mod l`.
+const R: Scalar52 = Scalar52([
+    0x000f48bd6721e6ed,
+    0x0003bab5ac67e45a,
+    0x000fffffeb35e51b,
+    0x000fffffffffffff,
+    0x00000fffffffffff,
+]);
+
+/// `R^2 = 2^520 mod l`.
+const RR: Scalar52 = Scalar52([
+    0x0009d265e952d13b,
+    0x000d63c715bea69f,
+    0x0005be65cb687604,
+    0x0003dceec73d217f,
+    0x000009411b7c309a,
+]);
+
+#[inline(always)]
+fn m(x: u64, y: u64) -> u128 {
+    (x as u128) * (y as u128)
+}
+
+impl Scalar52 {
+    fn zero() -> Scalar52 {
+        Scalar52([0; 5])
+    }
+
+    /// Unpack a 256-bit little-endian integer, without reduction.
+    fn from_bytes(bytes: &[u8; 32]) -> Scalar52 {
+        let mut words = [0u64; 4];
+        for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(8)) {
+            let mut buf = [0u8; 8];
+            buf.copy_from_slice(chunk);
+            *word = u64::from_le_bytes(buf);
+        }
+
+        Scalar52([
+            words[0] & MASK,
+            ((words[0] >> 52) | (words[1] << 12)) & MASK,
+            ((words[1] >> 40) | (words[2] << 24)) & MASK,
+            ((words[2] >> 28) | (words[3] << 36)) & MASK,
+            words[3] >> 16,
+        ])
+    }
+
+    /// Load a 512-bit little-endian integer and reduce it modulo `l`.
+    fn from_bytes_wide(bytes: &[u8; 64]) -> Scalar52 {
+        let mut words = [0u64; 8];
+        for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(8)) {
+            let mut buf = [0u8; 8];
+            buf.copy_from_slice(chunk);
+            *word = u64::from_le_bytes(buf);
+        }
+
+        let lo = Scalar52([
+            words[0] & MASK,
+            ((words[0] >> 52) | (words[1] << 12)) & MASK,
+            ((words[1] >> 40) | (words[2] << 24)) & MASK,
+            ((words[2] >> 28) | (words[3] << 36)) & MASK,
+            ((words[3] >> 16) | (words[4] << 48)) & MASK,
+        ]);
+        let hi = Scalar52([
+            (words[4] >> 4) & MASK,
+            ((words[4] >> 56) | (words[5] << 8)) & MASK,
+            ((words[5] >> 44) | (words[6] << 20)) & MASK,
+            ((words[6] >> 32) | (words[7] << 32)) & MASK,
+            words[7] >> 20,
+        ]);
+
+        // lo * R / R = lo and hi * R^2 / R = hi * 2^260 (mod l)
+        let lo = Scalar52::montgomery_mul(&lo, &R);
+        let hi = Scalar52::montgomery_mul(&hi, &RR);
+
+        Scalar52::add(&hi, &lo)
+    }
+
+    /// Pack a reduced scalar as 32 little-endian bytes.
+    fn into_scalar(self) -> Scalar {
+        let mut bytes = [0u8; 32];
+        let mut acc: u128 = 0;
+        let mut acc_bits = 0;
+        let mut i = 0;
+
+        for limb in self.0.iter() {
+            acc |= (*limb as u128) << acc_bits;
+            acc_bits += 52;
+            while acc_bits >= 8 && i < 32 {
+                bytes[i] = acc as u8;
+                acc >>= 8;
+                acc_bits -= 8;
+                i += 1;
+            }
+        }
+
+        Scalar { bytes }
+    }
+
+    /// Compute `a + b (mod l)` for reduced `a` and `b`.
+    fn add(a: &Scalar52, b: &Scalar52) -> Scalar52 {
+        let mut sum = Scalar52::zero();
+        let mut carry: u64 = 0;
+        for i in 0..5 {
+            carry = a.0[i] + b.0[i] + (carry >> 52);
+            sum.0[i] = carry & MASK;
+        }
+
+        Scalar52::sub(&sum, &L)
+    }
+
+    /// Compute `a - b (mod l)` for reduced `a` and `b`.
+    fn sub(a: &Scalar52, b: &Scalar52) -> Scalar52 {
+        let mut difference = Scalar52::zero();
+        let mut borrow: u64 = 0;
+        for i in 0..5 {
+            borrow = a.0[i].wrapping_sub(b.0[i] + (borrow >> 63));
+            difference.0[i] = borrow & MASK;
+        }
+
+        // Add l back if the subtraction underflowed
+        let underflow_mask = ((borrow >> 63) ^ 1).wrapping_sub(1);
+        let mut carry: u64 = 0;
+        for i in 0..5 {
+            carry = (carry >> 52) + difference.0[i] + (L.0[i] & underflow_mask);
+            difference.0[i] = carry & MASK;
+        }
+
+        difference
+    }
+
+    /// Compute `a * b (mod l)`.
+    fn mul(a: &Scalar52, b: &Scalar52) -> Scalar52 {
+        let ab = Scalar52::montgomery_mul(a, b);
+        Scalar52::montgomery_mul(&ab, &RR)
+    }
+
+    /// Compute `a * b / R (mod l)`.
+    fn montgomery_mul(a: &Scalar52, b: &Scalar52) -> Scalar52 {
+        Scalar52::montgomery_reduce(&Scalar52::mul_internal(a, b))
+    }
+
+    /// Schoolbook product of the limbs, without reduction.
+    fn mul_internal(a: &Scalar52, b: &Scalar52) -> [u128; 9] {
+        let mut z = [0u128; 9];
+        for i in 0..5 {
+            for j in 0..5 {
+                z[i + j] += m(a.0[i], b.0[j]);
+            }
+        }
+
+        z
+    }
+
+    /// Compute `limbs / R (mod l)`, where `limbs` is a 9-limb product.
+    fn montgomery_reduce(limbs: &[u128; 9]) -> Scalar52 {
+        // Pick n so that the low 52 bits of sum + n * l are zero.
+        #[inline(always)]
+        fn part1(sum: u128) -> (u128, u64) {
+            let p = (sum as u64).wrapping_mul(LFACTOR) & MASK;
+            ((sum + m(p, L.0[0])) >> 52, p)
+        }
+
+        #[inline(always)]
+        fn part2(sum: u128) -> (u128, u64) {
+            let w = (sum as u64) & MASK;
+            (sum >> 52, w)
+        }
+
+        // l.0[3] is zero, so its products are left out.
+        let l = &L.0;
+        let (carry, n0) = part1(limbs[0]);
+        let (carry, n1) = part1(carry + limbs[1] + m(n0, l[1]));
+        let (carry, n2) = part1(carry + limbs[2] + m(n0, l[2]) + m(n1, l[1]));
+        let (carry, n3) = part1(carry + limbs[3] + m(n1, l[2]) + m(n2, l[1]));
+        let (carry, n4) = part1(carry + limbs[4] + m(n0, l[4]) + m(n2, l[2]) + m(n3, l[1]));
+
+        let (carry, r0) = part2(carry + limbs[5] + m(n1, l[4]) + m(n3, l[2]) + m(n4, l[1]));
+        let (carry, r1) = part2(carry + limbs[6] + m(n2, l[4]) + m(n4, l[2]));
+        let (carry, r2) = part2(carry + limbs[7] + m(n3, l[4]));
+        let (carry, r3) = part2(carry + limbs[8] + m(n4, l[4]));
+        let r4 = carry as u64;
+
+        // The result is less than 2l, so one conditional subtraction reduces it.
+        Scalar52::sub(&Scalar52([r0, r1, r2, r3, r4]), &L)
+    }
+}
@@ -1,3 +1,20 @@
+//! SHA-512, as used by Ed25519, and its truncated variants SHA-384,
+//! SHA-512/224 and SHA-512/256.
+//!
+//! [`sha512`] is a `const fn` that hashes a complete message, so it can be
+//! evaluated at compile time. [`Sha512`] hashes a message that arrives in
+//! pieces. The truncated variants have the same pair of APIs; they only
+//! differ in the initial state and the output length. With the `digest`
+//! feature the hashers implement the RustCrypto `digest` traits, so they
+//! can be used wherever `D: Digest` is expected.
+#[cfg(feature = "digest")]
+use digest::{
+    consts::{U28, U32, U48, U64},
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update,
+};
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, Zeroizing};
+
 const BLOCK_SIZE: usize = 128;
 const RESULT_SIZE: usize = 64;
 const STATE_SIZE: usize = 8;
@@ -11,6 +28,37 @@ const INIT_STATE: [u64; STATE_SIZE] = [
     0x1f83d9abfb41bd6b,
     0x5be0cd19137e2179,
 ];
+// FIPS 180-4, section 5.3.4 and 5.3.6.
+const INIT_STATE_384: [u64; STATE_SIZE] = [
+    0xcbbb9d5dc1059ed8,
+    0x629a292a367cd507,
+    0x9159015a3070dd17,
+    0x152fecd8f70e5939,
+    0x67332667ffc00b31,
+    0x8eb44a8768581511,
+    0xdb0c2e0d64f98fa7,
+    0x47b5481dbefa4fa4,
+];
+const INIT_STATE_512_224: [u64; STATE_SIZE] = [
+    0x8c3d37c819544da2,
+    0x73e1996689dcd4d6,
+    0x1dfab7ae32ff9c82,
+    0x679dd514582f9fcf,
+    0x0f6d2b697bd44da8,
+    0x77e36f7304c48942,
+    0x3f9d85a86a1d36c8,
+    0x1112e6ad91d692a1,
+];
+const INIT_STATE_512_256: [u64; STATE_SIZE] = [
+    0x22312194fc2bf72c,
+    0x9f555fa3c84c64c2,
+    0x2393b86b6f53b151,
+    0x963877195940eabd,
+    0x96283ee2a88effe3,
+    0xbe5e1e2553863992,
+    0x2b0199fc2c85b8aa,
+    0x0eb72ddc81c52ca2,
+];
 const K512: [u64; 80] = [
     0x428a2f98d728ae22,
     0x7137449123ef65cd,
@@ -211,8 +259,23 @@ const fn sha512_transform(
     ]
 }
 
+/// Compute the SHA-512 hash of `input`.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::sha512::sha512;
+/// const EMPTY: [u8; 64] = sha512(b"");
+/// assert_eq!(EMPTY[..4], [0xcf, 0x83, 0xe1, 0x35]);
+/// ```
+///
 pub const fn sha512(input: &[u8]) -> [u8; RESULT_SIZE] {
-    let mut state = INIT_STATE;
+    hash(INIT_STATE, input)
+}
+
+/// Run the SHA-512 compression over the padded `input`, starting from `init`.
+const fn hash(init: [u64; STATE_SIZE], input: &[u8]) -> [u8; RESULT_SIZE] {
+    let mut state = init;
     let mut cursor = 0;
 
     while cursor + BLOCK_SIZE <= input.len() {
@@ -280,3 +343,313 @@ pub const fn sha512(input: &[u8]) -> [u8; RESULT_SIZE] {
         h[4], h[5], h[6], h[7],
     ]
 }
+
+/// Incremental SHA-512 hasher.
+///
+/// With the `zeroize` feature, the internal state is wiped when the hasher is dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_to_curve25519::sha512::{sha512, Sha512};
+/// let mut hasher = Sha512::new();
+/// hasher.update(b"hello ");
+/// hasher.update(b"world");
+/// assert_eq!(hasher.finalize(), sha512(b"hello world"));
+/// ```
+///
+#[derive(Clone)]
+pub struct Sha512 {
+    state: [u64; STATE_SIZE],
+    buffer: [u8; BLOCK_SIZE],
+    buffer_len: usize,
+    total_len: u128,
+}
+
+impl Default for Sha512 {
+    fn default() -> Sha512 {
+        Sha512::new()
+    }
+}
+
+impl Sha512 {
+    /// Construct a hasher for an empty message.
+    pub const fn new() -> Sha512 {
+        Sha512::with_state(INIT_STATE)
+    }
+
+    const fn with_state(state: [u64; STATE_SIZE]) -> Sha512 {
+        Sha512 {
+            state,
+            buffer: [0; BLOCK_SIZE],
+            buffer_len: 0,
+            total_len: 0,
+        }
+    }
+
+    /// Append `data` to the message.
+    pub fn update(&mut self, data: &[u8]) {
+        self.total_len = self.total_len.wrapping_add(data.len() as u128);
+
+        let mut data = data;
+        if self.buffer_len > 0 {
+            let take = core::cmp::min(BLOCK_SIZE - self.buffer_len, data.len());
+            self.buffer[self.buffer_len..self.buffer_len + take].copy_from_slice(&data[..take]);
+            self.buffer_len += take;
+            data = &data[take..];
+
+            if self.buffer_len < BLOCK_SIZE {
+                return;
+            }
+            self.state = sha512_transform(self.state, 0, &self.buffer);
+            self.buffer_len = 0;
+        }
+
+        let mut cursor = 0;
+        while cursor + BLOCK_SIZE <= data.len() {
+            self.state = sha512_transform(self.state, cursor, data);
+            cursor += BLOCK_SIZE;
+        }
+
+        let rest = data.len() - cursor;
+        self.buffer[..rest].copy_from_slice(&data[cursor..]);
+        self.buffer_len = rest;
+    }
+
+    /// Return the hash of the message.
+    pub fn finalize(mut self) -> [u8; RESULT_SIZE] {
+        let mut output = [0u8; RESULT_SIZE];
+        self.finish(&mut output);
+
+        output
+    }
+
+    /// Pad the message, process the last block(s) and write the hash to
+    /// `output`, which must be `RESULT_SIZE` bytes long.
+    fn finish(&mut self, output: &mut [u8]) {
+        let bit_len = self.total_len.wrapping_shl(3);
+
+        self.buffer[self.buffer_len] = 0x80;
+        for byte in self.buffer[self.buffer_len + 1..].iter_mut() {
+            *byte = 0;
+        }
+
+        // The 16-byte length does not fit after the terminator.
+        if self.buffer_len >= BLOCK_SIZE - 16 {
+            self.state = sha512_transform(self.state, 0, &self.buffer);
+            self.buffer = [0; BLOCK_SIZE];
+        }
+
+        self.buffer[BLOCK_SIZE - 16..].copy_from_slice(&bit_len.to_be_bytes());
+        self.state = sha512_transform(self.state, 0, &self.buffer);
+
+        for (chunk, word) in output.chunks_exact_mut(8).zip(self.state.iter()) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl HashMarker for Sha512 {}
+
+#[cfg(feature = "digest")]
+impl OutputSizeUser for Sha512 {
+    type OutputSize = U64;
+}
+
+#[cfg(feature = "digest")]
+impl Update for Sha512 {
+    fn update(&mut self, data: &[u8]) {
+        Sha512::update(self, data);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutput for Sha512 {
+    fn finalize_into(mut self, out: &mut Output<Self>) {
+        self.finish(out);
+    }
+}
+
+#[cfg(feature = "digest")]
+impl Reset for Sha512 {
+    fn reset(&mut self) {
+        *self = Sha512::new();
+    }
+}
+
+#[cfg(feature = "digest")]
+impl FixedOutputReset for Sha512 {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        self.finish(out);
+        self.reset();
+    }
+}
+
+/// First `N` bytes of a SHA-512 output.
+const fn truncate<const N: usize>(output: [u8; RESULT_SIZE]) -> [u8; N] {
+    let mut truncated = [0; N];
+    let mut i = 0;
+    while i < N {
+        truncated[i] = output[i];
+        i += 1;
+    }
+    truncated
+}
+
+macro_rules! truncated_sha512 {
+    (
+        $(#[$fn_doc:meta])*
+        fn $func:ident;
+        $(#[$doc:meta])*
+        struct $name:ident;
+        $init:ident, $size:literal, $output_size:ident
+    ) => {
+        $(#[$fn_doc])*
+        pub const fn $func(input: &[u8]) -> [u8; $size] {
+            truncate(hash($init, input))
+        }
+
+        $(#[$doc])*
+        #[derive(Clone)]
+        pub struct $name(Sha512);
+
+        impl Default for $name {
+            fn default() -> $name {
+                $name::new()
+            }
+        }
+
+        impl $name {
+            /// Construct a hasher for an empty message.
+            pub const fn new() -> $name {
+                $name(Sha512::with_state($init))
+            }
+
+            /// Append `data` to the message.
+            pub fn update(&mut self, data: &[u8]) {
+                self.0.update(data);
+            }
+
+            /// Return the hash of the message.
+            pub fn finalize(mut self) -> [u8; $size] {
+                let mut output = [0u8; RESULT_SIZE];
+                self.0.finish(&mut output);
+
+                truncate(output)
+            }
+        }
+
+        #[cfg(feature = "digest")]
+        impl HashMarker for $name {}
+
+        #[cfg(feature = "digest")]
+        impl OutputSizeUser for $name {
+            type OutputSize = $output_size;
+        }
+
+        #[cfg(feature = "digest")]
+        impl Update for $name {
+            fn update(&mut self, data: &[u8]) {
+                self.0.update(data);
+            }
+        }
+
+        #[cfg(feature = "digest")]
+        impl FixedOutput for $name {
+            fn finalize_into(self, out: &mut Output<Self>) {
+                out.copy_from_slice(&$name::finalize(self));
+            }
+        }
+
+        #[cfg(feature = "digest")]
+        impl Reset for $name {
+            fn reset(&mut self) {
+                *self = $name::new();
+            }
+        }
+
+        #[cfg(feature = "digest")]
+        impl FixedOutputReset for $name {
+            fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+                out.copy_from_slice(&self.clone().finalize());
+                self.reset();
+            }
+        }
+    };
+}
+
+truncated_sha512! {
+    /// Compute the SHA-384 hash of `input`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ed25519_to_curve25519::sha512::sha384;
+    /// const EMPTY: [u8; 48] = sha384(b"");
+    /// assert_eq!(EMPTY[..4], [0x38, 0xb0, 0x60, 0xa7]);
+    /// ```
+    ///
+    fn sha384;
+    /// Incremental SHA-384 hasher.
+    struct Sha384;
+    INIT_STATE_384, 48, U48
+}
+
+truncated_sha512! {
+    /// Compute the SHA-512/224 hash of `input`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ed25519_to_curve25519::sha512::sha512_224;
+    /// const EMPTY: [u8; 28] = sha512_224(b"");
+    /// assert_eq!(EMPTY[..4], [0x6e, 0xd0, 0xdd, 0x02]);
+    /// ```
+    ///
+    fn sha512_224;
+    /// Incremental SHA-512/224 hasher.
+    struct Sha512_224;
+    INIT_STATE_512_224, 28, U28
+}
+
+truncated_sha512! {
+    /// Compute the SHA-512/256 hash of `input`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ed25519_to_curve25519::sha512::sha512_256;
+    /// const EMPTY: [u8; 32] = sha512_256(b"");
+    /// assert_eq!(EMPTY[..4], [0xc6, 0x72, 0xb8, 0xd1]);
+    /// ```
+    ///
+    fn sha512_256;
+    /// Incremental SHA-512/256 hasher.
+    struct Sha512_256;
+    INIT_STATE_512_256, 32, U32
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Sha512 {
+    fn drop(&mut self) {
+        self.state.zeroize();
+        self.buffer.zeroize();
+    }
+}
+
+/// Compute SHA-512 of secret input.
+///
+/// Same result as `sha512`, but the hasher state and the output are
+/// wiped when they go out of scope. The message schedule inside
+/// `sha512_transform` is still left on the stack.
+#[cfg(feature = "zeroize")]
+pub(crate) fn sha512_zeroizing(input: &[u8]) -> Zeroizing<[u8; RESULT_SIZE]> {
+    let mut hasher = Sha512::new();
+    hasher.update(input);
+
+    let mut output = Zeroizing::new([0u8; RESULT_SIZE]);
+    hasher.finish(&mut output[..]);
+
+    output
+}
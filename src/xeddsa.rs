@@ -0,0 +1,245 @@
+//! XEdDSA and VXEdDSA, from Signal's "The XEdDSA and VXEdDSA Signature Schemes".
+#![allow(clippy::all)]
+#![allow(non_snake_case)]
+use crate::constants;
+use crate::ct::{Choice, ConditionallySelectable};
+use crate::ed25519;
+use crate::edwards::EdwardsPoint;
+use crate::error::Error;
+use crate::field::FieldElement;
+use crate::montgomery;
+use crate::scalar::Scalar;
+use crate::sha512::Sha512;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// Start `hash_i(X) = SHA-512(2^256 - 1 - i || X)`; the caller appends X.
+pub fn hash_i(i: u8) -> Sha512 {
+    let mut prefix = [0xff; 32];
+    prefix[0] = 0xff - i;
+
+    let mut hasher = Sha512::new();
+    hasher.update(&prefix);
+    hasher
+}
+
+/// Reduce a finished hash modulo `l`.
+pub fn hash_to_scalar(hasher: Sha512) -> Scalar {
+    Scalar::from_bytes_mod_order_wide(&hasher.finalize())
+}
+
+/// `calculate_key_pair`: the Edwards public key A with its sign bit
+/// cleared, and the private scalar `a` with `aB = A`.
+///
+/// `k` is the clamped X25519 scalar. If `kB` has a negative
+/// x-coordinate, `a = -k`, so `A` always has sign bit zero.
+#[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+pub fn calculate_key_pair(k: &[u8; 32]) -> ([u8; 32], Scalar) {
+    let mut A = EdwardsPoint::mul_base(k).compress();
+    let sign = Choice::from(A[31] >> 7);
+    A[31] &= 0x7f;
+
+    let mut k = Scalar::from_bytes_mod_order(*k);
+    let a = Scalar::conditional_select(&k, &-&k, sign);
+
+    #[cfg(feature = "zeroize")]
+    k.zeroize();
+
+    (A, a)
+}
+
+/// Sign `msg` with the X25519 secret key `sk`, using 64 bytes of fresh
+/// randomness `Z`.
+#[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+pub fn sign(sk: &[u8; 32], msg: &[u8], Z: &[u8; 64]) -> [u8; 64] {
+    let mut k = montgomery::clamp_scalar(*sk);
+    let (A, mut a) = calculate_key_pair(&k);
+
+    // r = hash_1(a || M || Z) (mod l)
+    let mut hasher = hash_i(1);
+    hasher.update(a.as_bytes());
+    hasher.update(msg);
+    hasher.update(Z);
+    let mut r = hash_to_scalar(hasher);
+    let R = EdwardsPoint::mul_base(r.as_bytes()).compress();
+
+    // h = hash(R || A || M) (mod l)
+    let mut hasher = Sha512::new();
+    hasher.update(&R);
+    hasher.update(&A);
+    hasher.update(msg);
+    let h = hash_to_scalar(hasher);
+
+    let s = Scalar::mul_add(&h, &a, &r);
+
+    #[cfg(feature = "zeroize")]
+    {
+        k.zeroize();
+        a.zeroize();
+        r.zeroize();
+    }
+
+    let mut signature = [0u8; 64];
+    signature[..32].copy_from_slice(&R);
+    signature[32..].copy_from_slice(&s.to_bytes());
+    signature
+}
+
+/// Verify the signature `R || s` on `msg` under the X25519 public key `u`.
+///
+/// `s` must already be checked to be reduced modulo `l`. The public key is
+/// converted with sign bit 0, as `calculate_key_pair` always produces,
+/// and the result is an Ed25519 signature under it.
+pub fn verify(u: &[u8; 32], msg: &[u8], R: &[u8; 32], s: &[u8; 32]) -> Result<(), Error> {
+    let A = crate::try_curve25519_pk_to_ed25519(*u, 0)?;
+    ed25519::verify(&A, msg, R, s, false)
+}
+
+/// Elligator 2: map a field element to the u-coordinate of a point on
+/// Curve25519 (not on its twist).
+fn elligator2(r: &FieldElement) -> FieldElement {
+    let A = FieldElement::from_bytes(&constants::MONTGOMERY_A);
+    let one = FieldElement::one();
+
+    // u1 = -A / (1 + 2r^2); the denominator is never zero, as -1/2 is not a square.
+    let rr2 = &r.square() + &r.square();
+    let u1 = &(-&A) * &(&one + &rr2).invert();
+    let w1 = &u1 * &(&(&u1.square() + &(&A * &u1)) + &one);
+
+    // Otherwise u2 = -A - u1 gives a square w2
+    let u2 = &(-&A) - &u1;
+    FieldElement::conditional_select(&u2, &u1, w1.is_square())
+}
+
+/// `hash_to_point`: hash `A || M` to a point in the prime-order subgroup.
+pub fn hash_to_point(A: &[u8; 32], msg: &[u8]) -> EdwardsPoint {
+    let mut hasher = hash_i(2);
+    hasher.update(A);
+    hasher.update(msg);
+    let h = hasher.finalize();
+
+    // The low 255 bits give r, bit 255 gives the sign of x
+    let mut r_bytes = [0u8; 32];
+    r_bytes.copy_from_slice(&h[..32]);
+    let sign = r_bytes[31] >> 7;
+    r_bytes[31] &= 0x7f;
+
+    // y = (u - 1)/(u + 1). u is on Curve25519, so x^2 = (y^2 - 1)/(dy^2 + 1)
+    // always has a root.
+    let u = elligator2(&FieldElement::from_bytes(&r_bytes));
+    let one = FieldElement::one();
+    let d = FieldElement::from_bytes(&constants::EDWARDS_D);
+    let Y = &(&u - &one) * &(&u + &one).invert();
+    let YY = Y.square();
+    let (_, mut X) = FieldElement::sqrt_ratio_i(&(&YY - &one), &(&(&YY * &d) + &one));
+
+    // The root is nonnegative; x = 0 has no negative root, so the sign bit is
+    // cleared there.
+    X.conditional_negate(Choice::from(sign) & !X.is_zero());
+
+    let P = EdwardsPoint {
+        X,
+        Y,
+        Z: one,
+        T: &X * &Y,
+    };
+
+    P.mul_by_cofactor()
+}
+
+/// `hash_5(cV)`, truncated to 32 bytes.
+fn vrf_output(V: &EdwardsPoint) -> [u8; 32] {
+    let mut hasher = hash_i(5);
+    hasher.update(&V.mul_by_cofactor().compress());
+
+    let mut v = [0u8; 32];
+    v.copy_from_slice(&hasher.finalize()[..32]);
+    v
+}
+
+/// `hash_4(A || V || R || Rv || M) (mod l)`
+fn vxeddsa_challenge(
+    A: &[u8; 32],
+    V: &[u8; 32],
+    R: &[u8; 32],
+    Rv: &[u8; 32],
+    msg: &[u8],
+) -> Scalar {
+    let mut hasher = hash_i(4);
+    hasher.update(A);
+    hasher.update(V);
+    hasher.update(R);
+    hasher.update(Rv);
+    hasher.update(msg);
+    hash_to_scalar(hasher)
+}
+
+/// VXEdDSA: sign `msg` with the X25519 secret key `sk` and return the
+/// signature `V || h || s` with the VRF output.
+#[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+pub fn vxeddsa_sign(sk: &[u8; 32], msg: &[u8], Z: &[u8; 64]) -> ([u8; 96], [u8; 32]) {
+    let mut k = montgomery::clamp_scalar(*sk);
+    let (A, mut a) = calculate_key_pair(&k);
+
+    let Bv = hash_to_point(&A, msg);
+    let V_point = Bv.mul(a.as_bytes());
+    let V = V_point.compress();
+
+    // r = hash_3(a || V || Z) (mod l)
+    let mut hasher = hash_i(3);
+    hasher.update(a.as_bytes());
+    hasher.update(&V);
+    hasher.update(Z);
+    let mut r = hash_to_scalar(hasher);
+    let R = EdwardsPoint::mul_base(r.as_bytes()).compress();
+    let Rv = Bv.mul(r.as_bytes()).compress();
+
+    let h = vxeddsa_challenge(&A, &V, &R, &Rv, msg);
+    let s = Scalar::mul_add(&h, &a, &r);
+
+    #[cfg(feature = "zeroize")]
+    {
+        k.zeroize();
+        a.zeroize();
+        r.zeroize();
+    }
+
+    let mut signature = [0u8; 96];
+    signature[..32].copy_from_slice(&V);
+    signature[32..64].copy_from_slice(&h.to_bytes());
+    signature[64..].copy_from_slice(&s.to_bytes());
+
+    (signature, vrf_output(&V_point))
+}
+
+/// VXEdDSA: verify the signature `V || h || s` on `msg` under the X25519
+/// public key `u` and return the VRF output.
+///
+/// `h` and `s` must already be checked to be reduced modulo `l`.
+pub fn vxeddsa_verify(
+    u: &[u8; 32],
+    msg: &[u8],
+    V: &[u8; 32],
+    h: &[u8; 32],
+    s: &[u8; 32],
+) -> Result<[u8; 32], Error> {
+    let A = crate::try_curve25519_pk_to_ed25519(*u, 0)?;
+    let A_point = EdwardsPoint::decompress(&A)?;
+    let V_point = EdwardsPoint::decompress(V)?;
+    let Bv = hash_to_point(&A, msg);
+
+    if A_point.is_small_order() || V_point.is_small_order() || Bv.is_identity() {
+        return Err(Error::SmallOrder);
+    }
+
+    // R = sB - hA, Rv = sBv - hV
+    let R = &EdwardsPoint::mul_base(s) + &(-&A_point.mul(h));
+    let Rv = &Bv.mul(s) + &(-&V_point.mul(h));
+
+    let h_check = vxeddsa_challenge(&A, V, &R.compress(), &Rv.compress(), msg);
+    if h_check.as_bytes() != h {
+        return Err(Error::InvalidSignature);
+    }
+
+    Ok(vrf_output(&V_point))
+}
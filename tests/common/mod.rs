@@ -0,0 +1,19 @@
+//! Helpers for reading the test vector files in `tests/data`.
+
+pub fn from_hex(hex: &str) -> Vec<u8> {
+    assert_eq!(hex.len() % 2, 0);
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+/// Value of a `Key = value` line.
+pub fn field<'a>(line: &'a str, key: &str) -> Option<&'a str> {
+    let (name, value) = line.split_once('=')?;
+    if name.trim() == key {
+        Some(value.trim())
+    } else {
+        None
+    }
+}
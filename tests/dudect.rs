@@ -0,0 +1,117 @@
+//! Dudect-style timing tests, after Reparaz, Balasch and Verbauwhede, "Dude, is my code constant
+//! time?".
+//!
+//! Each test times a conversion function on two classes of inputs, one fixed input and uniformly
+//! random inputs, interleaved in random order, and applies Welch's t-test to the two timing
+//! distributions. A |t| above `THRESHOLD` is strong evidence of a timing leak.
+//!
+//! Timings are noisy on shared machines, so these tests are ignored by default. Run them in
+//! release mode:
+//!
+//! ```text
+//! cargo test --release --test dudect -- --ignored
+//! ```
+use ed25519_to_curve25519::*;
+use std::hint::black_box;
+use std::time::Instant;
+
+const MEASUREMENTS: usize = 200_000;
+const THRESHOLD: f64 = 10.0;
+
+/// xorshift64* generator, good enough to pick classes and random inputs.
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545f4914f6cdd1d)
+    }
+
+    fn fill(&mut self, bytes: &mut [u8]) {
+        for chunk in bytes.chunks_mut(8) {
+            let word = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&word[..chunk.len()]);
+        }
+    }
+}
+
+/// Online mean and variance (Welford) for the two classes.
+#[derive(Default)]
+struct Welch {
+    n: [f64; 2],
+    mean: [f64; 2],
+    m2: [f64; 2],
+}
+
+impl Welch {
+    fn push(&mut self, class: usize, x: f64) {
+        self.n[class] += 1.0;
+        let delta = x - self.mean[class];
+        self.mean[class] += delta / self.n[class];
+        self.m2[class] += delta * (x - self.mean[class]);
+    }
+
+    fn t(&self) -> f64 {
+        let var0 = self.m2[0] / (self.n[0] - 1.0);
+        let var1 = self.m2[1] / (self.n[1] - 1.0);
+
+        (self.mean[0] - self.mean[1]) / (var0 / self.n[0] + var1 / self.n[1]).sqrt()
+    }
+}
+
+/// Return Welch's t statistic for `f` on a fixed input versus random inputs.
+fn t_statistic<F: Fn([u8; 32]) -> [u8; 32]>(f: F) -> f64 {
+    let mut rng = Rng(0x9e3779b97f4a7c15);
+
+    let mut fixed = [0u8; 32];
+    rng.fill(&mut fixed);
+
+    let mut classes = vec![0usize; MEASUREMENTS];
+    let mut inputs = vec![[0u8; 32]; MEASUREMENTS];
+    for (class, input) in classes.iter_mut().zip(inputs.iter_mut()) {
+        *class = (rng.next_u64() & 1) as usize;
+        if *class == 0 {
+            *input = fixed;
+        } else {
+            rng.fill(input);
+        }
+    }
+
+    let mut timings = vec![0u64; MEASUREMENTS];
+    for (timing, input) in timings.iter_mut().zip(inputs.iter()) {
+        let start = Instant::now();
+        black_box(f(black_box(*input)));
+        *timing = start.elapsed().as_nanos() as u64;
+    }
+
+    // Drop the slowest 5% of measurements, which are mostly interrupts
+    // and other noise unrelated to the input.
+    let mut sorted = timings.clone();
+    sorted.sort_unstable();
+    let cutoff = sorted[MEASUREMENTS * 95 / 100];
+
+    let mut welch = Welch::default();
+    for (class, timing) in classes.iter().zip(timings.iter()) {
+        if *timing <= cutoff {
+            welch.push(*class, *timing as f64);
+        }
+    }
+
+    welch.t()
+}
+
+#[test]
+#[ignore]
+fn dudect_ed25519_sk_to_curve25519() {
+    let t = t_statistic(ed25519_sk_to_curve25519);
+    assert!(t.abs() < THRESHOLD, "t = {}", t);
+}
+
+#[test]
+#[ignore]
+fn dudect_ed25519_pk_to_curve25519() {
+    let t = t_statistic(ed25519_pk_to_curve25519);
+    assert!(t.abs() < THRESHOLD, "t = {}", t);
+}
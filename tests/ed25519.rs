@@ -0,0 +1,93 @@
+//! Ed25519 signing and verification against the RFC 8032, section 7.1 test vectors.
+//!
+//! `tests/data/rfc8032.txt` holds TEST 1, 2, 3, 1024 and SHA(abc) as `SEED`, `PUB`, `MESSAGE` and
+//! `SIG` lines, with `""` for the empty message.
+mod common;
+
+use common::{field, from_hex};
+use ed25519_to_curve25519::*;
+
+struct Vector {
+    seed: [u8; 32],
+    public: [u8; 32],
+    msg: Vec<u8>,
+    sig: [u8; 64],
+}
+
+fn vectors() -> Vec<Vector> {
+    let lines: Vec<&str> = include_str!("data/rfc8032.txt")
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect();
+
+    lines
+        .chunks(4)
+        .map(|chunk| Vector {
+            seed: from_hex(field(chunk[0], "SEED").unwrap())
+                .try_into()
+                .unwrap(),
+            public: from_hex(field(chunk[1], "PUB").unwrap())
+                .try_into()
+                .unwrap(),
+            msg: from_hex(field(chunk[2], "MESSAGE").unwrap().trim_matches('"')),
+            sig: from_hex(field(chunk[3], "SIG").unwrap())
+                .try_into()
+                .unwrap(),
+        })
+        .collect()
+}
+
+#[test]
+fn rfc8032_sign() {
+    let vectors = vectors();
+    assert_eq!(vectors.len(), 5);
+
+    for (i, v) in vectors.iter().enumerate() {
+        assert_eq!(ed25519_public_from_seed(v.seed), v.public, "vector {}", i);
+        assert_eq!(ed25519_sign(v.seed, &v.msg)[..], v.sig[..], "vector {}", i);
+    }
+}
+
+#[test]
+fn rfc8032_verify() {
+    for (i, v) in vectors().iter().enumerate() {
+        assert_eq!(
+            ed25519_verify(v.public, &v.msg, &v.sig),
+            Ok(()),
+            "vector {}",
+            i
+        );
+        assert_eq!(
+            ed25519_verify_strict(v.public, &v.msg, &v.sig),
+            Ok(()),
+            "vector {}",
+            i
+        );
+
+        let mut msg = v.msg.clone();
+        msg.push(0);
+        assert_eq!(
+            ed25519_verify(v.public, &msg, &v.sig),
+            Err(Error::InvalidSignature),
+            "vector {}",
+            i
+        );
+    }
+}
+
+/// The converted key pair agrees on an X25519 shared secret.
+#[test]
+fn rfc8032_keys_convert() {
+    let vectors = vectors();
+    let (a, b) = (&vectors[0], &vectors[1]);
+
+    let shared_a = x25519(
+        ed25519_sk_to_curve25519(a.seed),
+        ed25519_pk_to_curve25519(b.public),
+    );
+    let shared_b = x25519(
+        ed25519_sk_to_curve25519(b.seed),
+        ed25519_pk_to_curve25519(a.public),
+    );
+    assert_eq!(shared_a, shared_b);
+}
@@ -0,0 +1,180 @@
+//! SHA-512 against the NIST SHAVS response files and the `sha2` crate.
+//!
+//! `tests/data/SHA512{ShortMsg,LongMsg,Monte}.rsp` and `tests/data/SHA384{ShortMsg,LongMsg}.rsp`
+//! are the byte-oriented CAVP vectors published by NIST for FIPS 180-4.
+//! `tests/data/SHA512_{224,256}{ShortMsg,LongMsg}.rsp` reuse the SHA-512 messages with digests
+//! computed by OpenSSL, since no NIST copy of those files is vendored.
+mod common;
+
+use common::{field, from_hex};
+use ed25519_to_curve25519::sha512::{
+    sha384, sha512, sha512_224, sha512_256, Sha384, Sha512, Sha512_224, Sha512_256,
+};
+use proptest::prelude::*;
+use sha2::Digest;
+
+/// `(message, digest)` pairs from a ShortMsg or LongMsg response file.
+fn messages(rsp: &str) -> Vec<(Vec<u8>, Vec<u8>)> {
+    let mut vectors = Vec::new();
+    let mut len = None;
+    let mut msg = None;
+
+    for line in rsp.lines() {
+        if let Some(value) = field(line, "Len") {
+            len = Some(value.parse::<usize>().unwrap() / 8);
+        } else if let Some(value) = field(line, "Msg") {
+            let mut bytes = from_hex(value);
+            bytes.truncate(len.take().unwrap());
+            msg = Some(bytes);
+        } else if let Some(value) = field(line, "MD") {
+            vectors.push((msg.take().unwrap(), from_hex(value)));
+        }
+    }
+
+    vectors
+}
+
+/// Check the one-shot function and the incremental hasher against every vector of `rsp`.
+macro_rules! check_messages {
+    ($rsp:expr, $count:expr, $hash:ident, $hasher:ident) => {{
+        let vectors = messages($rsp);
+        assert_eq!(vectors.len(), $count);
+
+        for (msg, md) in vectors.iter() {
+            assert_eq!($hash(msg)[..], md[..], "Len = {}", msg.len() * 8);
+
+            let mut hasher = $hasher::new();
+            for chunk in msg.chunks(97) {
+                hasher.update(chunk);
+            }
+            assert_eq!(hasher.finalize()[..], md[..], "Len = {}", msg.len() * 8);
+        }
+    }};
+}
+
+#[test]
+fn cavp_short_msg() {
+    check_messages!(include_str!("data/SHA512ShortMsg.rsp"), 129, sha512, Sha512);
+}
+
+#[test]
+fn cavp_long_msg() {
+    check_messages!(include_str!("data/SHA512LongMsg.rsp"), 128, sha512, Sha512);
+}
+
+#[test]
+fn cavp_sha384_short_msg() {
+    check_messages!(include_str!("data/SHA384ShortMsg.rsp"), 129, sha384, Sha384);
+}
+
+#[test]
+fn cavp_sha384_long_msg() {
+    check_messages!(include_str!("data/SHA384LongMsg.rsp"), 128, sha384, Sha384);
+}
+
+#[test]
+fn sha512_224_short_msg() {
+    check_messages!(
+        include_str!("data/SHA512_224ShortMsg.rsp"),
+        129,
+        sha512_224,
+        Sha512_224
+    );
+}
+
+#[test]
+fn sha512_224_long_msg() {
+    check_messages!(
+        include_str!("data/SHA512_224LongMsg.rsp"),
+        128,
+        sha512_224,
+        Sha512_224
+    );
+}
+
+#[test]
+fn sha512_256_short_msg() {
+    check_messages!(
+        include_str!("data/SHA512_256ShortMsg.rsp"),
+        129,
+        sha512_256,
+        Sha512_256
+    );
+}
+
+#[test]
+fn sha512_256_long_msg() {
+    check_messages!(
+        include_str!("data/SHA512_256LongMsg.rsp"),
+        128,
+        sha512_256,
+        Sha512_256
+    );
+}
+
+#[test]
+fn cavp_monte() {
+    let rsp = include_str!("data/SHA512Monte.rsp");
+    let mut seed = rsp
+        .lines()
+        .find_map(|line| field(line, "Seed"))
+        .map(from_hex)
+        .unwrap();
+
+    let mut checkpoints = 0;
+    for expected in rsp.lines().filter_map(|line| field(line, "MD")) {
+        let mut md = [seed.clone(), seed.clone(), seed.clone()];
+        for _ in 3..1003 {
+            let mut msg = Vec::with_capacity(3 * 64);
+            for previous in md.iter() {
+                msg.extend_from_slice(previous);
+            }
+            md = [md[1].clone(), md[2].clone(), sha512(&msg).to_vec()];
+        }
+
+        seed = md[2].clone();
+        assert_eq!(seed, from_hex(expected), "COUNT = {}", checkpoints);
+        checkpoints += 1;
+    }
+
+    assert_eq!(checkpoints, 100);
+}
+
+/// Lengths where the padding moves: the 0x80 terminator and the 16-byte length field just fit in
+/// the last block (111), spill into an extra block (112, 127), or the message fills whole blocks.
+#[test]
+fn padding_boundaries() {
+    let input: Vec<u8> = (0..1024u32).map(|i| (i * 31 + 7) as u8).collect();
+
+    for len in [
+        0, 1, 110, 111, 112, 113, 127, 128, 129, 239, 240, 255, 256, 257, 367, 368, 383, 384, 1024,
+    ] {
+        let msg = &input[..len];
+        let expected = sha2::Sha512::digest(msg);
+        assert_eq!(sha512(msg)[..], expected[..], "len = {}", len);
+
+        for split in [1, 111, 112, 127, 128]
+            .iter()
+            .filter(|split| **split <= len)
+        {
+            let mut hasher = Sha512::new();
+            hasher.update(&msg[..*split]);
+            hasher.update(&msg[*split..]);
+            assert_eq!(hasher.finalize()[..], expected[..], "len = {}", len);
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn matches_sha2(msg in proptest::collection::vec(any::<u8>(), 0..1024), split in any::<prop::sample::Index>()) {
+        let expected = sha2::Sha512::digest(&msg);
+        prop_assert_eq!(&sha512(&msg)[..], &expected[..]);
+
+        let split = split.index(msg.len() + 1);
+        let mut hasher = Sha512::new();
+        hasher.update(&msg[..split]);
+        hasher.update(&msg[split..]);
+        prop_assert_eq!(&hasher.finalize()[..], &expected[..]);
+    }
+}